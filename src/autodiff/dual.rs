@@ -0,0 +1,245 @@
+//! Forward-mode differentiation with dual numbers.
+//!
+//! Running a computation on [`Dual`] elements propagates a tangent alongside every value, so
+//! the output's tangents are the directional derivative of the computation along the input's
+//! tangents, with no tape involved.
+//!
+//! ```
+//! use tensr::{autodiff::dual, tensor::Vector};
+//!
+//! let a = Vector::<f64, 2>::from_array([3.0, 4.0]);
+//! let j = dual::jacobian(|x| x.clone() * x, a);
+//! assert_eq!((j[(0, 0)], j[(0, 1)], j[(1, 1)]), (6.0, 0.0, 8.0));
+//! ```
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    backend::Backend,
+    num::{One, Zero},
+    tensor::{Matrix, Vector},
+};
+
+/// A value paired with its derivative along some direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual<T> {
+    pub value: T,
+    pub tangent: T,
+}
+
+impl<T> Dual<T> {
+    pub fn new(value: T, tangent: T) -> Self {
+        Self { value, tangent }
+    }
+
+    /// A value that does not vary along the direction, with a zero tangent.
+    pub fn constant(value: T) -> Self
+    where
+        T: Zero,
+    {
+        Self::new(value, T::zero())
+    }
+}
+
+impl<T: Zero> Zero for Dual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+}
+
+impl<T: Zero + One> One for Dual<T> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.value + other.value, self.tangent + other.tangent)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.value - other.value, self.tangent - other.tangent)
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Copy> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.value * other.value,
+            self.tangent * other.value + self.value * other.tangent,
+        )
+    }
+}
+
+impl<T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let value = self.value / other.value;
+        let tangent = (self.tangent - value * other.tangent) / other.value;
+        Self::new(value, tangent)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.tangent)
+    }
+}
+
+/// Evaluates `f` at `x` and returns its value together with its derivative along `v`, the
+/// Jacobian-vector product.
+pub fn jvp<T, const N: usize, const M: usize, B>(
+    f: impl Fn(Vector<Dual<T>, N, B>) -> Vector<Dual<T>, M, B>,
+    x: Vector<T, N, B>,
+    v: Vector<T, N, B>,
+) -> (Vector<T, M, B>, Vector<T, M, B>)
+where
+    B: Backend<T> + Backend<Dual<T>>,
+{
+    let x = <B as Backend<T>>::t1_into_vec(x.repr);
+    let v = <B as Backend<T>>::t1_into_vec(v.repr);
+    let y = f(Vector {
+        repr: <B as Backend<Dual<T>>>::t1_from_vec(
+            x.into_iter().zip(v).map(|(x, v)| Dual::new(x, v)).collect(),
+        ),
+        shape: N,
+    });
+    let (values, tangents) = <B as Backend<Dual<T>>>::t1_into_vec(y.repr)
+        .into_iter()
+        .map(|y| (y.value, y.tangent))
+        .unzip();
+    (
+        Vector {
+            repr: <B as Backend<T>>::t1_from_vec(values),
+            shape: M,
+        },
+        Vector {
+            repr: <B as Backend<T>>::t1_from_vec(tangents),
+            shape: M,
+        },
+    )
+}
+
+/// Returns the `M x N` Jacobian of `f` at `x`, evaluating `f` once per input direction.
+pub fn jacobian<T, const N: usize, const M: usize, B>(
+    f: impl Fn(Vector<Dual<T>, N, B>) -> Vector<Dual<T>, M, B>,
+    x: Vector<T, N, B>,
+) -> Matrix<T, M, N, B>
+where
+    T: Zero + One + Copy,
+    B: Backend<T> + Backend<Dual<T>>,
+{
+    let x = <B as Backend<T>>::t1_into_vec(x.repr);
+    let mut jacobian = vec![T::zero(); M * N];
+    for j in 0..N {
+        let seeded = x
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| Dual::new(x, if i == j { T::one() } else { T::zero() }));
+        let y = f(Vector {
+            repr: <B as Backend<Dual<T>>>::t1_from_vec(seeded.collect()),
+            shape: N,
+        });
+        for (i, y) in <B as Backend<Dual<T>>>::t1_into_vec(y.repr)
+            .into_iter()
+            .enumerate()
+        {
+            jacobian[i * N + j] = y.tangent;
+        }
+    }
+    Matrix {
+        repr: <B as Backend<T>>::t2_from_vec(jacobian),
+        shape: (M, N),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{array, basic};
+
+    #[test]
+    fn test_arithmetic() {
+        let x = Dual::new(3.0_f64, 1.0);
+        let c = Dual::constant(2.0);
+        // d/dx (x^2 - 2x) / (x + 2) at x = 3
+        let y = (x * x - c * x) / (x + c);
+        assert_eq!(y.value, 0.6);
+        assert!((y.tangent - 0.68).abs() < 1e-12);
+        assert_eq!(-x, Dual::new(-3.0, -1.0));
+        assert_eq!(Dual::<i32>::one(), Dual::new(1, 0));
+    }
+
+    #[test]
+    fn test_matmul_directional_derivative() {
+        // d/dt (A + tV) B = V B
+        let a = Matrix::<Dual<i32>, 2, 2>::from_array([
+            [Dual::new(1, 1), Dual::new(2, 0)],
+            [Dual::new(3, 0), Dual::new(4, 1)],
+        ]);
+        let b = Matrix::<Dual<i32>, 2, 2>::from_array([
+            [Dual::constant(5), Dual::constant(6)],
+            [Dual::constant(7), Dual::constant(8)],
+        ]);
+        let c = a.matmul(b);
+        assert_eq!(c[(0, 0)], Dual::new(19, 5));
+        assert_eq!(c[(1, 1)], Dual::new(50, 8));
+    }
+
+    #[test]
+    fn test_dot() {
+        let x = Vector::<Dual<f64>, 2, basic::Backend>::from_array([
+            Dual::new(1.0, 1.0),
+            Dual::new(2.0, 0.0),
+        ]);
+        let y = x.clone().dot(x);
+        assert_eq!(*y.get(), Dual::new(5.0, 2.0));
+    }
+
+    #[test]
+    fn test_jvp() {
+        let m = Matrix::<Dual<i32>, 2, 3, array::Backend>::from_array([
+            [1, 2, 3].map(Dual::constant),
+            [4, 5, 6].map(Dual::constant),
+        ]);
+        let (y, dy) = jvp(
+            |x| m.matvec(x),
+            Vector::from_array([1, 1, 1]),
+            Vector::from_array([0, 1, 0]),
+        );
+        assert_eq!(y.repr, [6, 15]);
+        assert_eq!(dy.repr, [2, 5]);
+    }
+
+    #[test]
+    fn test_jacobian() {
+        let m = Matrix::<Dual<i32>, 2, 3>::from_array([
+            [1, 2, 3].map(Dual::constant),
+            [4, 5, 6].map(Dual::constant),
+        ]);
+        let j = jacobian(|x| m.clone().matvec(x), Vector::from_array([7, 8, 9]));
+        assert_eq!(j.repr.into_vec(), vec![1, 2, 3, 4, 5, 6]);
+
+        // f(x, y) = (x y, x + y)
+        let j = jacobian(
+            |v: Vector<Dual<f64>, 2>| {
+                let (x, y) = (v[0], v[1]);
+                Vector::from_array([x * y, x + y])
+            },
+            Vector::from_array([2.0, 3.0]),
+        );
+        assert_eq!(j.repr.into_vec(), vec![3.0, 2.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,647 @@
+//! Automatic differentiation over tensors of any rank and backend.
+//!
+//! In reverse mode, a [`Tape`] records every operation performed on its [`Var`]s. Calling
+//! [`Var::backward`] on a scalar loss replays the tape in reverse and returns [`Gradients`], from
+//! which the gradient with respect to each variable is read back with the variable's own shape
+//! type.
+//!
+//! ```
+//! use tensr::{autodiff::Tape, tensor::Matrix};
+//!
+//! let tape = Tape::new();
+//! let w = tape.var(Matrix::<f64, 2, 2>::from_array([[1.0, 2.0], [3.0, 4.0]]));
+//! let x = tape.var(Matrix::<f64, 2, 1>::from_array([[1.0], [-1.0]]));
+//! let loss = w.clone().matmul(x).sum();
+//!
+//! let grads = loss.backward();
+//! let dw: Matrix<f64, 2, 2> = grads.wrt(&w);
+//! assert_eq!(dw[(1, 1)], -1.0);
+//! ```
+//!
+//! Of the broadcasting operations, only [`Var::add_along`] on matrices is recorded, which covers
+//! adding a bias; the `t3_t2_*` broadcasts have no differentiable form.
+//!
+//! In forward mode, any computation is run on [`Dual`] numbers instead, which carry a
+//! directional derivative alongside each value; see [`dual::jacobian`].
+
+use std::{
+    cell::RefCell,
+    ops::{Add, Div, Mul, Neg, Sub},
+    rc::Rc,
+};
+
+use crate::{
+    backend::Backend,
+    num::{One, Zero},
+    tensor::{Matrix, Scalar, Tensor, Tensor3, Vector},
+};
+
+pub mod dual;
+
+pub use dual::Dual;
+
+/// Tensors that can be recorded on a [`Tape`], which stores their gradients as row-major
+/// buffers.
+pub trait Differentiable: Tensor<DataType: Clone + 'static> + Clone + 'static {
+    fn into_vec(self) -> Vec<Self::DataType>;
+    fn from_vec(a: Vec<Self::DataType>) -> Self;
+}
+
+impl<T: Clone + 'static, B: Backend<T> + Clone + 'static> Differentiable for Scalar<T, B> {
+    fn into_vec(self) -> Vec<T> {
+        vec![B::t0_into_value(self.repr)]
+    }
+
+    fn from_vec(a: Vec<T>) -> Self {
+        assert_eq!(a.len(), 1);
+        Scalar::new(a.into_iter().next().unwrap())
+    }
+}
+
+impl<T, const D0: usize, B> Differentiable for Vector<T, D0, B>
+where
+    T: Clone + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    fn into_vec(self) -> Vec<T> {
+        B::t1_into_vec(self.repr)
+    }
+
+    fn from_vec(a: Vec<T>) -> Self {
+        Vector {
+            repr: B::t1_from_vec(a),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B> Differentiable for Matrix<T, D0, D1, B>
+where
+    T: Clone + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    fn into_vec(self) -> Vec<T> {
+        B::t2_into_vec(self.repr)
+    }
+
+    fn from_vec(a: Vec<T>) -> Self {
+        Matrix {
+            repr: B::t2_from_vec(a),
+            shape: (D0, D1),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B> Differentiable
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Clone + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    fn into_vec(self) -> Vec<T> {
+        B::t3_into_vec(self.repr)
+    }
+
+    fn from_vec(a: Vec<T>) -> Self {
+        Tensor3 {
+            repr: B::t3_from_vec(a),
+            shape: (D0, D1, D2),
+        }
+    }
+}
+
+/// Maps the gradient of a node to the gradient contributions of its parents.
+type Backward<T> = Box<dyn Fn(&[T]) -> Vec<(usize, Vec<T>)>>;
+
+/// Records the operations performed on its variables, in order.
+pub struct Tape<T> {
+    nodes: Rc<RefCell<Vec<Backward<T>>>>,
+}
+
+impl<T> Clone for Tape<T> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: Rc::clone(&self.nodes),
+        }
+    }
+}
+
+impl<T: 'static> Default for Tape<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Tape<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    /// Starts tracking `value` as a leaf, such as a parameter or an input.
+    pub fn var<X: Differentiable<DataType = T>>(&self, value: X) -> Var<X> {
+        Var {
+            value,
+            id: self.push(Box::new(|_| Vec::new())),
+            tape: self.clone(),
+        }
+    }
+
+    fn push(&self, backward: Backward<T>) -> usize {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(backward);
+        nodes.len() - 1
+    }
+}
+
+/// A tensor whose operations are recorded on a [`Tape`].
+#[derive(Clone)]
+pub struct Var<X: Tensor> {
+    value: X,
+    id: usize,
+    tape: Tape<X::DataType>,
+}
+
+impl<X: Differentiable> Var<X> {
+    pub fn value(&self) -> &X {
+        &self.value
+    }
+
+    pub fn into_value(self) -> X {
+        self.value
+    }
+
+    fn unary<Y>(&self, value: Y, backward: impl Fn(Y) -> X + 'static) -> Var<Y>
+    where
+        Y: Differentiable<DataType = X::DataType>,
+    {
+        let parent = self.id;
+        let backward =
+            move |g: &[X::DataType]| vec![(parent, backward(Y::from_vec(g.to_vec())).into_vec())];
+        Var {
+            value,
+            id: self.tape.push(Box::new(backward)),
+            tape: self.tape.clone(),
+        }
+    }
+
+    fn binary<O, Y>(
+        &self,
+        other: &Var<O>,
+        value: Y,
+        backward: impl Fn(Y) -> (X, O) + 'static,
+    ) -> Var<Y>
+    where
+        O: Differentiable<DataType = X::DataType>,
+        Y: Differentiable<DataType = X::DataType>,
+    {
+        assert!(
+            Rc::ptr_eq(&self.tape.nodes, &other.tape.nodes),
+            "variables are recorded on different tapes"
+        );
+        let parents = (self.id, other.id);
+        let backward = move |g: &[X::DataType]| {
+            let (a, b) = backward(Y::from_vec(g.to_vec()));
+            vec![(parents.0, a.into_vec()), (parents.1, b.into_vec())]
+        };
+        Var {
+            value,
+            id: self.tape.push(Box::new(backward)),
+            tape: self.tape.clone(),
+        }
+    }
+}
+
+impl<T, B> Var<Scalar<T, B>>
+where
+    T: Add<Output = T> + One + Copy + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    /// Differentiates this loss with respect to every variable recorded before it.
+    pub fn backward(&self) -> Gradients<T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut grads: Vec<Option<Vec<T>>> = vec![None; self.id + 1];
+        grads[self.id] = Some(vec![T::one()]);
+        for id in (0..=self.id).rev() {
+            let contributions = match &grads[id] {
+                Some(g) => nodes[id](g),
+                None => continue,
+            };
+            for (parent, contribution) in contributions {
+                grads[parent] = Some(match grads[parent].take() {
+                    Some(acc) => acc
+                        .into_iter()
+                        .zip(contribution)
+                        .map(|(a, b)| a + b)
+                        .collect(),
+                    None => contribution,
+                });
+            }
+        }
+        Gradients { grads }
+    }
+}
+
+/// The gradients of a loss, as returned by [`Var::backward`].
+pub struct Gradients<T> {
+    grads: Vec<Option<Vec<T>>>,
+}
+
+impl<T: Clone> Gradients<T> {
+    /// Returns the gradient with respect to `var`, which is zero if the loss does not depend on
+    /// it.
+    pub fn wrt<X: Differentiable<DataType = T>>(&self, var: &Var<X>) -> X
+    where
+        T: Zero + Copy,
+    {
+        match self.grads.get(var.id) {
+            Some(Some(g)) => X::from_vec(g.clone()),
+            _ => X::zeros(),
+        }
+    }
+}
+
+impl<X> Add for Var<X>
+where
+    X: Differentiable + Add<Output = X>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let value = self.value.clone() + other.value.clone();
+        self.binary(&other, value, |g: X| (g.clone(), g))
+    }
+}
+
+impl<X> Sub for Var<X>
+where
+    X: Differentiable + Sub<Output = X> + Neg<Output = X>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let value = self.value.clone() - other.value.clone();
+        self.binary(&other, value, |g: X| (g.clone(), -g))
+    }
+}
+
+impl<X> Mul for Var<X>
+where
+    X: Differentiable + Mul<Output = X>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = (self.value.clone(), other.value.clone());
+        let value = a.clone() * b.clone();
+        self.binary(&other, value, move |g: X| {
+            (g.clone() * b.clone(), g * a.clone())
+        })
+    }
+}
+
+impl<X> Div for Var<X>
+where
+    X: Differentiable + Mul<Output = X> + Div<Output = X> + Neg<Output = X>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let value = self.value.clone() / other.value.clone();
+        let b = other.value.clone();
+        self.binary(&other, value.clone(), move |g: X| {
+            quotient_grads(g, value.clone(), b.clone())
+        })
+    }
+}
+
+impl<X> Neg for Var<X>
+where
+    X: Differentiable + Neg<Output = X>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let value = -self.value.clone();
+        self.unary(value, |g: X| -g)
+    }
+}
+
+impl<T, const D0: usize, B> Var<Vector<T, D0, B>>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + One + Copy + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    pub fn dot(self, other: Self) -> Var<Scalar<T, B>> {
+        let (a, b) = (self.value.clone(), other.value.clone());
+        let value = a.clone().dot(b.clone());
+        self.binary(&other, value, move |g: Scalar<T, B>| {
+            (b.clone() * g.clone(), a.clone() * g)
+        })
+    }
+
+    pub fn sum(self) -> Var<Scalar<T, B>> {
+        let value = self.value.clone().sum();
+        self.unary(value, |g| Vector::ones() * g)
+    }
+
+    pub fn permute<const P0: usize>(self) -> Self {
+        assert_eq!(P0, 0, "improper permute");
+        self
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B> Var<Matrix<T, D0, D1, B>>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + One + Copy + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    pub fn matmul<const OD1: usize>(
+        self,
+        other: Var<Matrix<T, D1, OD1, B>>,
+    ) -> Var<Matrix<T, D0, OD1, B>> {
+        let (a, b) = (self.value.clone(), other.value.clone());
+        let value = a.clone().matmul(b.clone());
+        self.binary(&other, value, move |g: Matrix<T, D0, OD1, B>| {
+            (
+                g.clone().matmul(transpose(b.clone())),
+                transpose(a.clone()).matmul(g),
+            )
+        })
+    }
+
+    /// Adds `other` to every slice of `self` along axis `A`, as for a bias. The gradient of
+    /// `other` is the sum of the incoming gradient over the remaining axis.
+    pub fn add_along<const A: usize>(
+        self,
+        other: Var<
+            Vector<
+                T,
+                {
+                    Matrix::<T, D0, D1, B>::calculate_along(
+                        A,
+                        Matrix::<T, D0, D1, B>::construct_shape(D0, D1),
+                    )
+                },
+                B,
+            >,
+        >,
+    ) -> Self {
+        let value = self.value.clone().add_along::<A>(other.value.clone());
+        self.binary(&other, value, |g: Matrix<T, D0, D1, B>| {
+            let g = g.into_vec();
+            let mut sums = vec![T::zero(); if A == 0 { D0 } else { D1 }];
+            for (l, &x) in g.iter().enumerate() {
+                let k = if A == 0 { l / D1 } else { l % D1 };
+                sums[k] = sums[k] + x;
+            }
+            (Matrix::from_vec(g), Vector::from_vec(sums))
+        })
+    }
+
+    pub fn sum(self) -> Var<Scalar<T, B>> {
+        let value = self.value.clone().sum();
+        self.unary(value, |g| Matrix::ones() * g)
+    }
+
+    pub fn permute<const P0: usize, const P1: usize>(
+        self,
+    ) -> Var<
+        Matrix<
+            T,
+            {
+                Matrix::<T, D0, D1, B>::calculate_permute(
+                    Matrix::<T, D0, D1, B>::construct_shape(P0, P1),
+                    Matrix::<T, D0, D1, B>::construct_shape(D0, D1),
+                    0,
+                )
+            },
+            {
+                Matrix::<T, D0, D1, B>::calculate_permute(
+                    Matrix::<T, D0, D1, B>::construct_shape(P0, P1),
+                    Matrix::<T, D0, D1, B>::construct_shape(D0, D1),
+                    1,
+                )
+            },
+            B,
+        >,
+    > {
+        let value = self.value.clone().permute::<P0, P1>();
+        self.unary(value, |g| {
+            Matrix::from_vec(unpermute(&g.into_vec(), [D0, D1], [P0, P1]))
+        })
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B> Var<Tensor3<T, D0, D1, D2, B>>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + One + Copy + 'static,
+    B: Backend<T> + Clone + 'static,
+{
+    pub fn sum(self) -> Var<Scalar<T, B>> {
+        let value = self.value.clone().sum();
+        self.unary(value, |g| Tensor3::ones() * g)
+    }
+
+    pub fn permute<const P0: usize, const P1: usize, const P2: usize>(
+        self,
+    ) -> Var<
+        Tensor3<
+            T,
+            {
+                Tensor3::<T, D0, D1, D2, B>::calculate_permute(
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(P0, P1, P2),
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(D0, D1, D2),
+                    0,
+                )
+            },
+            {
+                Tensor3::<T, D0, D1, D2, B>::calculate_permute(
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(P0, P1, P2),
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(D0, D1, D2),
+                    1,
+                )
+            },
+            {
+                Tensor3::<T, D0, D1, D2, B>::calculate_permute(
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(P0, P1, P2),
+                    Tensor3::<T, D0, D1, D2, B>::construct_shape(D0, D1, D2),
+                    2,
+                )
+            },
+            B,
+        >,
+    > {
+        let value = self.value.clone().permute::<P0, P1, P2>();
+        self.unary(value, |g| {
+            Tensor3::from_vec(unpermute(&g.into_vec(), [D0, D1, D2], [P0, P1, P2]))
+        })
+    }
+}
+
+/// Returns the gradients of `a / b` with respect to `a` and `b`, given the quotient.
+fn quotient_grads<X>(g: X, quotient: X, b: X) -> (X, X)
+where
+    X: Clone + Mul<Output = X> + Div<Output = X> + Neg<Output = X>,
+{
+    let da = g / b;
+    (da.clone(), -(da * quotient))
+}
+
+fn transpose<T: Zero + Copy, const D0: usize, const D1: usize, B: Backend<T>>(
+    a: Matrix<T, D0, D1, B>,
+) -> Matrix<T, D1, D0, B> {
+    Matrix {
+        repr: B::t2_transpose(a.repr),
+        shape: (D1, D0),
+    }
+}
+
+/// Gathers `g`, the row-major gradient of a tensor of `shape` permuted by `p`, back into the
+/// layout of the unpermuted tensor.
+fn unpermute<T: Copy, const N: usize>(g: &[T], shape: [usize; N], p: [usize; N]) -> Vec<T> {
+    let permuted = p.map(|axis| shape[axis]);
+    (0..g.len())
+        .map(|mut l| {
+            let mut index = [0; N];
+            for (i, d) in index.iter_mut().zip(shape).rev() {
+                *i = l % d;
+                l /= d;
+            }
+            let offset = p
+                .iter()
+                .zip(permuted)
+                .fold(0, |offset, (&axis, d)| offset * d + index[axis]);
+            g[offset]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::array;
+
+    #[test]
+    fn test_elementwise() {
+        let tape = Tape::new();
+        let x = tape.var(Vector::<f64, 3>::from_array([1.0, 2.0, 4.0]));
+        let y = tape.var(Vector::<f64, 3>::from_array([2.0, -1.0, 0.5]));
+        let loss = ((x.clone() * y.clone() + x.clone() - y.clone()) / y.clone()).sum();
+
+        let grads = loss.backward();
+        // d/dx (x + x / y - 1) = 1 + 1 / y, d/dy = -x / y^2
+        assert_eq!(grads.wrt(&x).repr.into_vec(), vec![1.5, 0.0, 3.0]);
+        assert_eq!(grads.wrt(&y).repr.into_vec(), vec![-0.25, -2.0, -16.0]);
+    }
+
+    #[test]
+    fn test_neg_and_reuse() {
+        let tape = Tape::new();
+        let x = tape.var(Scalar::<i32, array::Backend>::new(3));
+        let loss = -(x.clone() * x.clone() * x.clone());
+        assert_eq!(*loss.value().get(), -27);
+        assert_eq!(*loss.backward().wrt(&x).get(), -27);
+    }
+
+    #[test]
+    fn test_dot() {
+        let tape = Tape::new();
+        let x = tape.var(Vector::<i32, 2>::from_array([1, 2]));
+        let y = tape.var(Vector::<i32, 2>::from_array([3, 4]));
+        let grads = x.clone().dot(y.clone()).backward();
+        assert_eq!(grads.wrt(&x).repr.into_vec(), vec![3, 4]);
+        assert_eq!(grads.wrt(&y).repr.into_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_matmul() {
+        let tape = Tape::new();
+        let a = tape.var(Matrix::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]));
+        let b = tape.var(Matrix::<i32, 3, 1>::from_array([[1], [0], [-1]]));
+        let c = tape.var(Matrix::<i32, 2, 1>::from_array([[2], [3]]));
+        let loss = (a.clone().matmul(b.clone()) * c).sum();
+
+        let grads = loss.backward();
+        // dL/da = c b^T, dL/db = a^T c
+        let da: Matrix<i32, 2, 3> = grads.wrt(&a);
+        let db: Matrix<i32, 3, 1> = grads.wrt(&b);
+        assert_eq!(da.repr.into_vec(), vec![2, 0, -2, 3, 0, -3]);
+        assert_eq!(db.repr.into_vec(), vec![14, 19, 24]);
+    }
+
+    #[test]
+    fn test_add_along() {
+        let tape = Tape::new();
+        let a = tape.var(Matrix::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]));
+        let w = tape.var(Matrix::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]));
+        let rows = tape.var(Vector::<i32, 2>::from_array([10, 20]));
+        let cols = tape.var(Vector::<i32, 3>::from_array([1, 0, -1]));
+        let biased = a
+            .clone()
+            .add_along::<0>(rows.clone())
+            .add_along::<1>(cols.clone());
+        assert_eq!(
+            biased.value().clone().repr.into_vec(),
+            vec![12, 12, 12, 25, 25, 25]
+        );
+
+        let grads = (biased * w).sum().backward();
+        assert_eq!(grads.wrt(&a).repr.into_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(grads.wrt(&rows).repr.into_vec(), vec![6, 15]);
+        assert_eq!(grads.wrt(&cols).repr.into_vec(), vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn test_permute() {
+        let tape = Tape::new();
+        let a = tape.var(Matrix::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]));
+        let w = tape.var(Matrix::<i32, 3, 2>::from_array([[1, 2], [3, 4], [5, 6]]));
+        let grads = (a.clone().permute::<1, 0>() * w).sum().backward();
+        assert_eq!(grads.wrt(&a).repr.into_vec(), vec![1, 3, 5, 2, 4, 6]);
+
+        let t = tape.var(Tensor3::<i32, 1, 2, 3>::from_array([[
+            [1, 2, 3],
+            [4, 5, 6],
+        ]]));
+        let w = tape.var(Tensor3::<i32, 3, 2, 1>::from_array([
+            [[1], [2]],
+            [[3], [4]],
+            [[5], [6]],
+        ]));
+        let grads = (t.clone().permute::<2, 1, 0>() * w).sum().backward();
+        assert_eq!(grads.wrt(&t).repr.into_vec(), vec![1, 3, 5, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_unreached_variable() {
+        let tape = Tape::new();
+        let x = tape.var(Vector::<i32, 2>::from_array([1, 2]));
+        let unused = tape.var(Matrix::<i32, 2, 2>::from_array([[1, 2], [3, 4]]));
+        let grads = x.sum().backward();
+        assert_eq!(grads.wrt(&unused).repr.into_vec(), vec![0; 4]);
+    }
+
+    #[test]
+    fn test_gradient_descent() {
+        let target = Vector::<f64, 2, array::Backend>::from_array([1.0, -2.0]);
+        let mut w = Vector::<f64, 2, array::Backend>::zeros();
+        for _ in 0..100 {
+            let tape = Tape::new();
+            let param = tape.var(w);
+            let error = param.clone() - tape.var(target);
+            let grad = (error.clone() * error).sum().backward().wrt(&param);
+            w = w - grad * Scalar::new(0.1);
+        }
+        assert!((w[0] - 1.0).abs() < 1e-6 && (w[1] + 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "variables are recorded on different tapes")]
+    fn test_different_tapes() {
+        let x = Tape::new().var(Scalar::<i32, array::Backend>::new(1));
+        let y = Tape::new().var(Scalar::<i32, array::Backend>::new(2));
+        let _ = x + y;
+    }
+}
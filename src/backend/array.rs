@@ -0,0 +1,2565 @@
+use std::array;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::backend::{argmax, argmin, max, mean, min, prod, sum, Backend as BackendTrait};
+use crate::num::{One, Zero};
+
+/// Stores every tensor inline as nested fixed-size arrays.
+///
+/// Nothing is heap-allocated outside of the `Vec` conversions, and every repr is `Copy`, so small
+/// tensors such as 3×3 and 4×4 transforms can be passed around by value for free.
+#[derive(Clone, Copy, Debug)]
+pub struct Backend {}
+
+impl<T: Copy> BackendTrait<T> for Backend {
+    type Index = usize;
+    type Dimension = usize;
+    type T0Repr = T;
+    type T1Repr<const D0: usize> = [T; D0];
+    type T2Repr<const D0: usize, const D1: usize> = [[T; D1]; D0];
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize> = [[[T; D2]; D1]; D0];
+
+    fn t0_from_value(v: T) -> Self::T0Repr {
+        v
+    }
+
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0> {
+        a
+    }
+
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1> {
+        a
+    }
+
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a
+    }
+
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0> {
+        assert_eq!(v.len(), D0);
+        array::from_fn(|i| v[i])
+    }
+
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1> {
+        assert_eq!(v.len(), D0 * D1);
+        array::from_fn(|i| array::from_fn(|j| v[i * D1 + j]))
+    }
+
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        assert_eq!(v.len(), D0 * D1 * D2);
+        array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| v[(i * D1 + j) * D2 + k])))
+    }
+
+    fn t0_into_value(a: Self::T0Repr) -> T {
+        a
+    }
+
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T> {
+        a.to_vec()
+    }
+
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T> {
+        a.concat()
+    }
+
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T> {
+        a.concat().concat()
+    }
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0> {
+        assert_eq!(D0, RD0);
+        array::from_fn(|i| a[i])
+    }
+
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        assert_eq!(D0, RD0 * RD1);
+        array::from_fn(|i| array::from_fn(|j| a[i * RD1 + j]))
+    }
+
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        assert_eq!(D0, RD0 * RD1 * RD2);
+        array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| a[(i * RD1 + j) * RD2 + k])))
+    }
+
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0> {
+        assert_eq!(D0 * D1, RD0);
+        let at = |n: usize| a[n / D1][n % D1];
+        array::from_fn(at)
+    }
+
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        assert_eq!(D0 * D1, RD0 * RD1);
+        let at = |n: usize| a[n / D1][n % D1];
+        array::from_fn(|i| array::from_fn(|j| at(i * RD1 + j)))
+    }
+
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        assert_eq!(D0 * D1, RD0 * RD1 * RD2);
+        let at = |n: usize| a[n / D1][n % D1];
+        array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| at((i * RD1 + j) * RD2 + k))))
+    }
+
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0> {
+        assert_eq!(D0 * D1 * D2, RD0);
+        let at = |n: usize| a[n / (D1 * D2)][n / D2 % D1][n % D2];
+        array::from_fn(at)
+    }
+
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        assert_eq!(D0 * D1 * D2, RD0 * RD1);
+        let at = |n: usize| a[n / (D1 * D2)][n / D2 % D1][n % D2];
+        array::from_fn(|i| array::from_fn(|j| at(i * RD1 + j)))
+    }
+
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        assert_eq!(D0 * D1 * D2, RD0 * RD1 * RD2);
+        let at = |n: usize| a[n / (D1 * D2)][n / D2 % D1][n % D2];
+        array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| at((i * RD1 + j) * RD2 + k))))
+    }
+
+    fn t0_get(a: &Self::T0Repr) -> &T {
+        a
+    }
+
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T {
+        a
+    }
+
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        &a[i]
+    }
+
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        &mut a[i]
+    }
+
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        &a[i][j]
+    }
+
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        &mut a[i][j]
+    }
+
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        &a[i][j][k]
+    }
+
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        &mut a[i][j][k]
+    }
+
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        a.get_unchecked(i)
+    }
+
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        a.get_unchecked_mut(i)
+    }
+
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        a.get_unchecked(i).get_unchecked(j)
+    }
+
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        a.get_unchecked_mut(i).get_unchecked_mut(j)
+    }
+
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        a.get_unchecked(i).get_unchecked(j).get_unchecked(k)
+    }
+
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        a.get_unchecked_mut(i)
+            .get_unchecked_mut(j)
+            .get_unchecked_mut(k)
+    }
+
+    fn t0_zero() -> Self::T0Repr
+    where
+        T: Zero,
+    {
+        T::zero()
+    }
+
+    fn t0_one() -> Self::T0Repr
+    where
+        T: One,
+    {
+        T::one()
+    }
+
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: Zero + Copy,
+    {
+        [T::zero(); D0]
+    }
+
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: One + Copy,
+    {
+        [T::one(); D0]
+    }
+
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: Zero + Copy,
+    {
+        [[T::zero(); D1]; D0]
+    }
+
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: One + Copy,
+    {
+        [[T::one(); D1]; D0]
+    }
+
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: One + Copy,
+    {
+        [[[T::one(); D2]; D1]; D0]
+    }
+
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Zero + Copy,
+    {
+        [[[T::zero(); D2]; D1]; D0]
+    }
+
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
+    where
+        T: Zero + One + Copy,
+    {
+        array::from_fn(|i| array::from_fn(|j| if i == j { T::one() } else { T::zero() }))
+    }
+
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
+    where
+        T: Zero + Copy,
+    {
+        array::from_fn(|i| array::from_fn(|j| a[j][i]))
+    }
+
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (usize, usize),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy,
+    {
+        let p = [p.0, p.1];
+        let d = check_permute(p, [D0, D1]);
+        assert_eq!(d, [RD0, RD1]);
+        array::from_fn(|i| {
+            array::from_fn(|j| {
+                let mut index = [0; 2];
+                (index[p[0]], index[p[1]]) = (i, j);
+                a[index[0]][index[1]]
+            })
+        })
+    }
+
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        p: (usize, usize, usize),
+    ) -> Self::T3Repr<RD0, RD1, RD2>
+    where
+        T: Zero + Copy,
+    {
+        let p = [p.0, p.1, p.2];
+        let d = check_permute(p, [D0, D1, D2]);
+        assert_eq!(d, [RD0, RD1, RD2]);
+        array::from_fn(|i| {
+            array::from_fn(|j| {
+                array::from_fn(|k| {
+                    let mut index = [0; 3];
+                    (index[p[0]], index[p[1]], index[p[2]]) = (i, j, k);
+                    a[index[0]][index[1]][index[2]]
+                })
+            })
+        })
+    }
+
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0> {
+        assert_eq!(RD0, D0 + OD0);
+        array::from_fn(|i| if i < D0 { a[i] } else { b[i - D0] })
+    }
+
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1> {
+        let d = check_concat([D0, D1], [OD0, OD1], axis);
+        assert_eq!(d, [RD0, RD1]);
+        array::from_fn(|i| {
+            array::from_fn(|j| {
+                let mut index = [i, j];
+                if index[axis] < [D0, D1][axis] {
+                    a[i][j]
+                } else {
+                    index[axis] -= [D0, D1][axis];
+                    b[index[0]][index[1]]
+                }
+            })
+        })
+    }
+
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: usize,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        let d = check_concat([D0, D1, D2], [OD0, OD1, OD2], axis);
+        assert_eq!(d, [RD0, RD1, RD2]);
+        array::from_fn(|i| {
+            array::from_fn(|j| {
+                array::from_fn(|k| {
+                    let mut index = [i, j, k];
+                    if index[axis] < [D0, D1, D2][axis] {
+                        a[i][j][k]
+                    } else {
+                        index[axis] -= [D0, D1, D2][axis];
+                        b[index[0]][index[1]][index[2]]
+                    }
+                })
+            })
+        })
+    }
+
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1> {
+        a
+    }
+
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a
+    }
+
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        at: usize,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>) {
+        assert_eq!([LD0, RD0], [at, D0 - at]);
+        (array::from_fn(|i| a[i]), array::from_fn(|i| a[at + i]))
+    }
+
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>) {
+        let (left, right) = check_split([D0, D1], at, axis);
+        assert_eq!((left, right), ([LD0, LD1], [RD0, RD1]));
+        let mut offset = [0; 2];
+        offset[axis] = at;
+        (
+            array::from_fn(|i| array::from_fn(|j| a[i][j])),
+            array::from_fn(|i| array::from_fn(|j| a[i + offset[0]][j + offset[1]])),
+        )
+    }
+
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>) {
+        let (left, right) = check_split([D0, D1, D2], at, axis);
+        assert_eq!((left, right), ([LD0, LD1, LD2], [RD0, RD1, RD2]));
+        let mut offset = [0; 3];
+        offset[axis] = at;
+        (
+            array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| a[i][j][k]))),
+            array::from_fn(|i| {
+                array::from_fn(|j| {
+                    array::from_fn(|k| a[i + offset[0]][j + offset[1]][k + offset[2]])
+                })
+            }),
+        )
+    }
+
+    fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Add<Output = T>,
+    {
+        a + b
+    }
+
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        a.map(|a| a + b)
+    }
+
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T>,
+    {
+        zip1(a, b, T::add)
+    }
+
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a + b))
+    }
+
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        broadcast2(a, b, along, T::add)
+    }
+
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T>,
+    {
+        zip2(a, b, T::add)
+    }
+
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a.map(|a| a + b)))
+    }
+
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        broadcast3(a, b, along, T::add)
+    }
+
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        a.map(|a| zip2(a, b, T::add))
+    }
+
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T>,
+    {
+        zip3(a, b, T::add)
+    }
+
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>,
+    {
+        a - b
+    }
+
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.map(|a| a - b)
+    }
+
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>,
+    {
+        zip1(a, b, T::sub)
+    }
+
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a - b))
+    }
+
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        broadcast2(a, b, along, T::sub)
+    }
+
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>,
+    {
+        zip2(a, b, T::sub)
+    }
+
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a.map(|a| a - b)))
+    }
+
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        broadcast3(a, b, along, T::sub)
+    }
+
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.map(|a| zip2(a, b, T::sub))
+    }
+
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>,
+    {
+        zip3(a, b, T::sub)
+    }
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>,
+    {
+        a * b
+    }
+
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.map(|a| a * b)
+    }
+
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>,
+    {
+        zip1(a, b, T::mul)
+    }
+
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a * b))
+    }
+
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        broadcast2(a, b, along, T::mul)
+    }
+
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>,
+    {
+        zip2(a, b, T::mul)
+    }
+
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a.map(|a| a * b)))
+    }
+
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        broadcast3(a, b, along, T::mul)
+    }
+
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.map(|a| zip2(a, b, T::mul))
+    }
+
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>,
+    {
+        zip3(a, b, T::mul)
+    }
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>,
+    {
+        a / b
+    }
+
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.map(|a| a / b)
+    }
+
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>,
+    {
+        zip1(a, b, T::div)
+    }
+
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a / b))
+    }
+
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        broadcast2(a, b, along, T::div)
+    }
+
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>,
+    {
+        zip2(a, b, T::div)
+    }
+
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a.map(|a| a / b)))
+    }
+
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        broadcast3(a, b, along, T::div)
+    }
+
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.map(|a| zip2(a, b, T::div))
+    }
+
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>,
+    {
+        zip3(a, b, T::div)
+    }
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>,
+    {
+        a % b
+    }
+
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.map(|a| a % b)
+    }
+
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>,
+    {
+        zip1(a, b, T::rem)
+    }
+
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a % b))
+    }
+
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        broadcast2(a, b, along, T::rem)
+    }
+
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>,
+    {
+        zip2(a, b, T::rem)
+    }
+
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.map(|a| a.map(|a| a.map(|a| a % b)))
+    }
+
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        broadcast3(a, b, along, T::rem)
+    }
+
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.map(|a| zip2(a, b, T::rem))
+    }
+
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>,
+    {
+        zip3(a, b, T::rem)
+    }
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Neg<Output = T>,
+    {
+        -a
+    }
+
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>,
+    {
+        a.map(T::neg)
+    }
+
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>,
+    {
+        a.map(|a| a.map(T::neg))
+    }
+
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>,
+    {
+        a.map(|a| a.map(|a| a.map(T::neg)))
+    }
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr {
+        f(a)
+    }
+
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0> {
+        a.map(f)
+    }
+
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        a.map(|a| a.map(&f))
+    }
+
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a.map(|a| a.map(|a| a.map(&f)))
+    }
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr {
+        f(a, b)
+    }
+
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0> {
+        zip1(a, b, f)
+    }
+
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        zip2(a, b, f)
+    }
+
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        zip3(a, b, f)
+    }
+
+    fn t0_fold<U>(a: Self::T0Repr, init: U, mut f: impl FnMut(U, T) -> U) -> U {
+        f(init, a)
+    }
+
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U {
+        a.into_iter().fold(init, f)
+    }
+
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_iter().flatten().fold(init, f)
+    }
+
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_iter().flatten().flatten().fold(init, f)
+    }
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        sum(&a)
+    }
+
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        prod(&a)
+    }
+
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        min(&a)
+    }
+
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        max(&a)
+    }
+
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        mean(&a)
+    }
+
+    fn t1_argmin<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmin(&a)
+    }
+
+    fn t1_argmax<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmax(&a)
+    }
+
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        reduce2(a, axis, sum)
+    }
+
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        reduce2(a, axis, prod)
+    }
+
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce2(a, axis, min)
+    }
+
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce2(a, axis, max)
+    }
+
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        reduce2(a, axis, mean)
+    }
+
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> [usize; RD0]
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce2(a, axis, argmin)
+    }
+
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> [usize; RD0]
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce2(a, axis, argmax)
+    }
+
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        reduce3(a, axis, sum)
+    }
+
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        reduce3(a, axis, prod)
+    }
+
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce3(a, axis, min)
+    }
+
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce3(a, axis, max)
+    }
+
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        reduce3(a, axis, mean)
+    }
+
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> [[usize; RD1]; RD0]
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce3(a, axis, argmin)
+    }
+
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> [[usize; RD1]; RD0]
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce3(a, axis, argmax)
+    }
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        dot(a, b)
+    }
+
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        a.map(|a| dot(a, b))
+    }
+
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        array::from_fn(|j| dot(a, b.map(|b| b[j])))
+    }
+
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let b = Self::t2_transpose(b);
+        a.map(|a| b.map(|b| dot(a, b)))
+    }
+
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        a.map(|a| Self::t2_t2_matmul(a, b))
+    }
+
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        array::from_fn(|i| Self::t2_t2_matmul(a[i], b[i]))
+    }
+}
+
+/// Returns the shape `d` takes on under the permutation `p`.
+fn check_permute<const N: usize>(p: [usize; N], d: [usize; N]) -> [usize; N] {
+    let mut seen = [false; N];
+    for &axis in &p {
+        assert!(axis < N && !seen[axis], "improper permute");
+        seen[axis] = true;
+    }
+    p.map(|axis| d[axis])
+}
+
+/// Returns the shape of `a` and `b` joined along `axis`.
+fn check_concat<const N: usize>(a: [usize; N], b: [usize; N], axis: usize) -> [usize; N] {
+    assert!(axis < N, "improper axis");
+    for i in (0..N).filter(|&i| i != axis) {
+        assert_eq!(a[i], b[i]);
+    }
+    let mut d = a;
+    d[axis] += b[axis];
+    d
+}
+
+/// Returns the shapes of the two halves of `d` split at `at` along `axis`.
+fn check_split<const N: usize>(d: [usize; N], at: usize, axis: usize) -> ([usize; N], [usize; N]) {
+    assert!(axis < N && at <= d[axis], "improper split");
+    let (mut left, mut right) = (d, d);
+    left[axis] = at;
+    right[axis] = d[axis] - at;
+    (left, right)
+}
+
+fn zip1<T: Copy, const D0: usize>(a: [T; D0], b: [T; D0], f: impl Fn(T, T) -> T) -> [T; D0] {
+    array::from_fn(|i| f(a[i], b[i]))
+}
+
+fn zip2<T: Copy, const D0: usize, const D1: usize>(
+    a: [[T; D1]; D0],
+    b: [[T; D1]; D0],
+    f: impl Fn(T, T) -> T,
+) -> [[T; D1]; D0] {
+    array::from_fn(|i| zip1(a[i], b[i], &f))
+}
+
+fn zip3<T: Copy, const D0: usize, const D1: usize, const D2: usize>(
+    a: [[[T; D2]; D1]; D0],
+    b: [[[T; D2]; D1]; D0],
+    f: impl Fn(T, T) -> T,
+) -> [[[T; D2]; D1]; D0] {
+    array::from_fn(|i| zip2(a[i], b[i], &f))
+}
+
+/// Combines every element of `a` with the element of `b` at its index along `along`.
+fn broadcast2<T: Copy, const D0: usize, const D1: usize, const OD0: usize>(
+    a: [[T; D1]; D0],
+    b: [T; OD0],
+    along: usize,
+    f: impl Fn(T, T) -> T,
+) -> [[T; D1]; D0] {
+    assert_eq!([D0, D1][along], OD0);
+    array::from_fn(|i| array::from_fn(|j| f(a[i][j], b[[i, j][along]])))
+}
+
+/// Combines every element of `a` with the element of `b` at its index along `along`.
+fn broadcast3<T: Copy, const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+    a: [[[T; D2]; D1]; D0],
+    b: [T; OD0],
+    along: usize,
+    f: impl Fn(T, T) -> T,
+) -> [[[T; D2]; D1]; D0] {
+    assert_eq!([D0, D1, D2][along], OD0);
+    array::from_fn(|i| array::from_fn(|j| array::from_fn(|k| f(a[i][j][k], b[[i, j, k][along]]))))
+}
+
+/// Applies `f` to every lane along `axis`, gathering columns into a stack buffer first.
+fn reduce2<T: Copy, U, const D0: usize, const D1: usize, const RD0: usize>(
+    a: [[T; D1]; D0],
+    axis: usize,
+    f: impl Fn(&[T]) -> U,
+) -> [U; RD0] {
+    match axis {
+        0 => {
+            assert_eq!(RD0, D1);
+            array::from_fn(|j| f(&a.map(|a| a[j])))
+        }
+        1 => {
+            assert_eq!(RD0, D0);
+            array::from_fn(|i| f(&a[i]))
+        }
+        _ => panic!("improper axis"),
+    }
+}
+
+/// Applies `f` to every lane along `axis`, keeping the remaining axes in order.
+fn reduce3<
+    T: Copy,
+    U,
+    const D0: usize,
+    const D1: usize,
+    const D2: usize,
+    const RD0: usize,
+    const RD1: usize,
+>(
+    a: [[[T; D2]; D1]; D0],
+    axis: usize,
+    f: impl Fn(&[T]) -> U,
+) -> [[U; RD1]; RD0] {
+    match axis {
+        0 => {
+            assert_eq!([RD0, RD1], [D1, D2]);
+            array::from_fn(|j| array::from_fn(|k| f(&a.map(|a| a[j][k]))))
+        }
+        1 => {
+            assert_eq!([RD0, RD1], [D0, D2]);
+            array::from_fn(|i| array::from_fn(|k| f(&a[i].map(|a| a[k]))))
+        }
+        2 => {
+            assert_eq!([RD0, RD1], [D0, D1]);
+            array::from_fn(|i| array::from_fn(|j| f(&a[i][j])))
+        }
+        _ => panic!("improper axis"),
+    }
+}
+
+fn dot<T, const D0: usize>(a: [T; D0], b: [T; D0]) -> T
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero,
+{
+    a.into_iter()
+        .zip(b)
+        .fold(T::zero(), |acc, (a, b)| acc + a * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t0_from_value() {
+        let value: u8 = Backend::t0_from_value(7);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_t1_from_array() {
+        let a: [u8; 3] = Backend::t1_from_array([1, 2, 3]);
+        assert_eq!(a, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_t2_from_array() {
+        let a: [[u8; 3]; 2] = Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_t3_from_array() {
+        let a: [[[u8; 2]; 2]; 2] = Backend::t3_from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert_eq!(a, [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+    }
+
+    #[test]
+    fn test_t2_from_vec() {
+        let a: [[u8; 2]; 3] = Backend::t2_from_vec(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, [[1, 2], [3, 4], [5, 6]]);
+    }
+
+    #[test]
+    fn test_t3_from_vec() {
+        let a: [[[u8; 2]; 1]; 3] = Backend::t3_from_vec(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, [[[1, 2]], [[3, 4]], [[5, 6]]]);
+    }
+
+    #[test]
+    fn test_t2_into_vec() {
+        assert_eq!(
+            Backend::t2_into_vec([[1, 2, 3], [4, 5, 6]]),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_t3_into_vec() {
+        assert_eq!(
+            Backend::t3_into_vec([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_reshape() {
+        let a: [[u8; 3]; 2] = Backend::t1_reshape2([1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+        let b: [[[u8; 2]; 1]; 3] = Backend::t2_reshape3(a);
+        assert_eq!(b, [[[1, 2]], [[3, 4]], [[5, 6]]]);
+        let c: [[u8; 2]; 3] = Backend::t3_reshape2(b);
+        assert_eq!(c, [[1, 2], [3, 4], [5, 6]]);
+        let d: [u8; 6] = Backend::t3_reshape1([[[1, 2, 3]], [[4, 5, 6]]]);
+        assert_eq!(d, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_t0_get() {
+        let mut a: u8 = 3;
+        assert_eq!(*Backend::t0_get(&a), 3);
+        *Backend::t0_get_mut(&mut a) = 4;
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn test_t1_get() {
+        let mut a = [1, 2, 3];
+        assert_eq!(*Backend::t1_get(&a, 1), 2);
+        *Backend::t1_get_mut(&mut a, 1) = 5;
+        assert_eq!(a, [1, 5, 3]);
+    }
+
+    #[test]
+    fn test_t2_get() {
+        let mut a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(*Backend::t2_get(&a, 1, 2), 6);
+        *Backend::t2_get_mut(&mut a, 1, 2) = 7;
+        assert_eq!(a, [[1, 2, 3], [4, 5, 7]]);
+    }
+
+    #[test]
+    fn test_t3_get() {
+        let mut a = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(*Backend::t3_get(&a, 1, 0, 1), 6);
+        *Backend::t3_get_mut(&mut a, 1, 0, 1) = 9;
+        assert_eq!(a, [[[1, 2], [3, 4]], [[5, 9], [7, 8]]]);
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut a = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        unsafe {
+            assert_eq!(*Backend::t3_get_unchecked(&a, 0, 1, 0), 3);
+            *Backend::t3_get_unchecked_mut(&mut a, 0, 1, 0) = 0;
+            assert_eq!(*Backend::t2_get_unchecked(&a[1], 1, 1), 8);
+            assert_eq!(*Backend::t1_get_unchecked(&a[1][0], 0), 5);
+        }
+        assert_eq!(a[0][1][0], 0);
+    }
+
+    #[test]
+    fn test_t0_zero() {
+        let zero: u8 = Backend::t0_zero();
+        assert_eq!(zero, 0);
+    }
+
+    #[test]
+    fn test_t0_one() {
+        let one: u8 = Backend::t0_one();
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn test_t1_zeros() {
+        let zeros: [u8; 3] = Backend::t1_zeros();
+        assert_eq!(zeros, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_t1_ones() {
+        let ones: [u8; 3] = Backend::t1_ones();
+        assert_eq!(ones, [1, 1, 1]);
+    }
+
+    #[test]
+    fn test_t2_zeros() {
+        let zeros: [[u8; 3]; 2] = Backend::t2_zeros();
+        assert_eq!(zeros, [[0, 0, 0], [0, 0, 0]]);
+    }
+
+    #[test]
+    fn test_t2_ones() {
+        let ones: [[u8; 3]; 2] = Backend::t2_ones();
+        assert_eq!(ones, [[1, 1, 1], [1, 1, 1]]);
+    }
+
+    #[test]
+    fn test_t3_zeros() {
+        let zeros: [[[u8; 4]; 3]; 2] = Backend::t3_zeros();
+        assert_eq!(
+            zeros,
+            [
+                [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]],
+                [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_ones() {
+        let ones: [[[u8; 4]; 3]; 2] = Backend::t3_ones();
+        assert_eq!(
+            ones,
+            [
+                [[1, 1, 1, 1], [1, 1, 1, 1], [1, 1, 1, 1]],
+                [[1, 1, 1, 1], [1, 1, 1, 1], [1, 1, 1, 1]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t2_identity() {
+        let eye: [[u8; 3]; 3] = Backend::t2_identity();
+        assert_eq!(eye, [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    }
+
+    #[test]
+    fn test_t2_transpose() {
+        assert_eq!(
+            Backend::t2_transpose([[1, 2, 3], [4, 5, 6]]),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+    }
+
+    #[test]
+    fn test_t2_permute() {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(Backend::t2_permute(a, (0, 1)), a);
+        assert_eq!(Backend::t2_permute(a, (1, 0)), [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn test_t3_permute() {
+        let input = [
+            [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+            [[10, 11, 12], [13, 14, 15], [16, 17, 18]],
+        ];
+        let permuted = Backend::t3_permute(input, (2, 0, 1));
+        let expected = [
+            [[1, 4, 7], [10, 13, 16]],
+            [[2, 5, 8], [11, 14, 17]],
+            [[3, 6, 9], [12, 15, 18]],
+        ];
+        assert_eq!(permuted, expected);
+    }
+
+    #[test]
+    fn test_t1_concat() {
+        assert_eq!(Backend::t1_concat([1, 2], [3, 4, 5]), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_t2_concat() {
+        let a = [[1, 2], [3, 4]];
+        assert_eq!(Backend::t2_concat(a, [[5, 6]], 0), [[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(Backend::t2_concat(a, [[5], [6]], 1), [[1, 2, 5], [3, 4, 6]]);
+    }
+
+    #[test]
+    fn test_t3_concat() {
+        let a = [[[1, 2]], [[3, 4]]];
+        assert_eq!(
+            Backend::t3_concat(a, [[[5, 6]]], 0),
+            [[[1, 2]], [[3, 4]], [[5, 6]]]
+        );
+        assert_eq!(
+            Backend::t3_concat(a, [[[5, 6]], [[7, 8]]], 1),
+            [[[1, 2], [5, 6]], [[3, 4], [7, 8]]]
+        );
+        assert_eq!(
+            Backend::t3_concat(a, [[[5]], [[6]]], 2),
+            [[[1, 2, 5]], [[3, 4, 6]]]
+        );
+    }
+
+    #[test]
+    fn test_t2_stack() {
+        assert_eq!(Backend::t2_stack([[1, 2], [3, 4]]), [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn test_t3_stack() {
+        assert_eq!(
+            Backend::t3_stack([[[1, 2]], [[3, 4]]]),
+            [[[1, 2]], [[3, 4]]]
+        );
+    }
+
+    #[test]
+    fn test_t1_split() {
+        assert_eq!(Backend::t1_split([1, 2, 3, 4, 5], 2), ([1, 2], [3, 4, 5]));
+    }
+
+    #[test]
+    fn test_t2_split() {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(Backend::t2_split(a, 1, 0), ([[1, 2, 3]], [[4, 5, 6]]));
+        assert_eq!(Backend::t2_split(a, 1, 1), ([[1], [4]], [[2, 3], [5, 6]]));
+    }
+
+    #[test]
+    fn test_t3_split() {
+        let a = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(
+            Backend::t3_split(a, 1, 0),
+            ([[[1, 2], [3, 4]]], [[[5, 6], [7, 8]]])
+        );
+        assert_eq!(
+            Backend::t3_split(a, 1, 1),
+            ([[[1, 2]], [[5, 6]]], [[[3, 4]], [[7, 8]]])
+        );
+        assert_eq!(
+            Backend::t3_split(a, 1, 2),
+            ([[[1], [3]], [[5], [7]]], [[[2], [4]], [[6], [8]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_add() {
+        assert_eq!(Backend::t0_t0_add(1, 2), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_add() {
+        assert_eq!(Backend::t1_t0_add([1, 2, 3], 2), [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_t1_t1_add() {
+        assert_eq!(Backend::t1_t1_add([1, 2, 3], [4, 5, 6]), [5, 7, 9]);
+    }
+
+    #[test]
+    fn test_t2_t0_add() {
+        assert_eq!(
+            Backend::t2_t0_add([[1, 2, 3], [4, 5, 6]], 2),
+            [[3, 4, 5], [6, 7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_0() {
+        assert_eq!(
+            Backend::t2_t1_add([[1, 2, 3], [4, 5, 6]], [2, 3], 0),
+            [[3, 4, 5], [7, 8, 9]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_1() {
+        assert_eq!(
+            Backend::t2_t1_add([[1, 2, 3], [4, 5, 6]], [2, 3, 4], 1),
+            [[3, 5, 7], [6, 8, 10]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_add() {
+        assert_eq!(
+            Backend::t2_t2_add([[1, 2, 3], [4, 5, 6]], [[2, 3, 4], [5, 6, 7]]),
+            [[3, 5, 7], [9, 11, 13]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_sub() {
+        assert_eq!(Backend::t0_t0_sub(9, 3), 6);
+    }
+
+    #[test]
+    fn test_t1_t0_sub() {
+        assert_eq!(Backend::t1_t0_sub([6, 8, 9], 2), [4, 6, 7]);
+    }
+
+    #[test]
+    fn test_t1_t1_sub() {
+        assert_eq!(Backend::t1_t1_sub([6, 8, 9], [4, 3, 2]), [2, 5, 7]);
+    }
+
+    #[test]
+    fn test_t2_t0_sub() {
+        assert_eq!(
+            Backend::t2_t0_sub([[6, 8, 9], [10, 12, 15]], 2),
+            [[4, 6, 7], [8, 10, 13]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t2_t1_sub([[6, 8, 9], [10, 12, 15]], [2, 4], 0),
+            [[4, 6, 7], [6, 8, 11]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t2_t1_sub([[6, 8, 9], [10, 12, 15]], [2, 3, 4], 1),
+            [[4, 5, 5], [8, 9, 11]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_sub() {
+        assert_eq!(
+            Backend::t2_t2_sub([[6, 8, 9], [10, 12, 15]], [[4, 3, 2], [3, 5, 7]]),
+            [[2, 5, 7], [7, 7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_mul() {
+        assert_eq!(Backend::t0_t0_mul(9, 3), 27);
+    }
+
+    #[test]
+    fn test_t1_t0_mul() {
+        assert_eq!(Backend::t1_t0_mul([6, 8, 9], 2), [12, 16, 18]);
+    }
+
+    #[test]
+    fn test_t1_t1_mul() {
+        assert_eq!(Backend::t1_t1_mul([6, 8, 9], [4, 3, 2]), [24, 24, 18]);
+    }
+
+    #[test]
+    fn test_t2_t0_mul() {
+        assert_eq!(
+            Backend::t2_t0_mul([[6, 8, 9], [10, 12, 15]], 2),
+            [[12, 16, 18], [20, 24, 30]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t2_t1_mul([[6, 8, 9], [10, 12, 15]], [2, 4], 0),
+            [[12, 16, 18], [40, 48, 60]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t2_t1_mul([[6, 8, 9], [10, 12, 15]], [2, 3, 4], 1),
+            [[12, 24, 36], [20, 36, 60]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_mul() {
+        assert_eq!(
+            Backend::t2_t2_mul([[6, 8, 9], [10, 12, 15]], [[4, 3, 2], [3, 5, 7]]),
+            [[24, 24, 18], [30, 60, 105]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_div() {
+        assert_eq!(Backend::t0_t0_div(9, 3), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_div() {
+        assert_eq!(Backend::t1_t0_div([6, 8, 9], 2), [3, 4, 4]);
+    }
+
+    #[test]
+    fn test_t1_t1_div() {
+        assert_eq!(Backend::t1_t1_div([6, 8, 9], [4, 3, 2]), [1, 2, 4]);
+    }
+
+    #[test]
+    fn test_t2_t0_div() {
+        assert_eq!(
+            Backend::t2_t0_div([[6, 8, 9], [10, 12, 15]], 2),
+            [[3, 4, 4], [5, 6, 7]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_0() {
+        assert_eq!(
+            Backend::t2_t1_div([[6, 8, 9], [10, 12, 15]], [2, 4], 0),
+            [[3, 4, 4], [2, 3, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_1() {
+        assert_eq!(
+            Backend::t2_t1_div([[6, 8, 9], [10, 12, 15]], [2, 3, 4], 1),
+            [[3, 2, 2], [5, 4, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_div() {
+        assert_eq!(
+            Backend::t2_t2_div([[6, 8, 9], [10, 12, 15]], [[4, 3, 2], [3, 5, 7]]),
+            [[1, 2, 4], [3, 2, 2]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_rem() {
+        assert_eq!(Backend::t0_t0_rem(9, 3), 0);
+    }
+
+    #[test]
+    fn test_t1_t0_rem() {
+        assert_eq!(Backend::t1_t0_rem([6, 8, 9], 2), [0, 0, 1]);
+    }
+
+    #[test]
+    fn test_t1_t1_rem() {
+        assert_eq!(Backend::t1_t1_rem([6, 8, 9], [4, 3, 2]), [2, 2, 1]);
+    }
+
+    #[test]
+    fn test_t2_t0_rem() {
+        assert_eq!(
+            Backend::t2_t0_rem([[6, 8, 9], [10, 12, 15]], 2),
+            [[0, 0, 1], [0, 0, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t2_t1_rem([[6, 8, 9], [10, 12, 15]], [2, 4], 0),
+            [[0, 0, 1], [2, 0, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t2_t1_rem([[6, 8, 9], [10, 12, 15]], [2, 3, 4], 1),
+            [[0, 2, 1], [0, 0, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_rem() {
+        assert_eq!(
+            Backend::t2_t2_rem([[6, 8, 9], [10, 12, 15]], [[4, 3, 2], [3, 5, 7]]),
+            [[2, 2, 1], [1, 2, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_add() {
+        assert_eq!(
+            Backend::t3_t0_add([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], 2),
+            [[[8, 10], [11, 12]], [[14, 16], [17, 20]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_0() {
+        assert_eq!(
+            Backend::t3_t1_add([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [2, 3], 0),
+            [[[8, 10], [11, 12]], [[15, 17], [18, 21]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_1() {
+        assert_eq!(
+            Backend::t3_t1_add([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [3, 4], 1),
+            [[[9, 11], [13, 14]], [[15, 17], [19, 22]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_2() {
+        assert_eq!(
+            Backend::t3_t1_add([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [4, 5], 2),
+            [[[10, 13], [13, 15]], [[16, 19], [19, 23]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_add() {
+        assert_eq!(
+            Backend::t3_t2_add([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [[2, 3], [4, 5]]),
+            [[[8, 11], [13, 15]], [[14, 17], [19, 23]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_add() {
+        assert_eq!(
+            Backend::t3_t3_add(
+                [[[6, 8], [9, 10]], [[12, 14], [15, 18]]],
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+            ),
+            [[[7, 10], [12, 14]], [[17, 20], [22, 26]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_sub() {
+        assert_eq!(
+            Backend::t3_t0_sub([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], 2),
+            [[[4, 6], [7, 8]], [[10, 12], [13, 16]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t3_t1_sub([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [2, 3], 0),
+            [[[4, 6], [7, 8]], [[9, 11], [12, 15]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t3_t1_sub([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [3, 4], 1),
+            [[[3, 5], [5, 6]], [[9, 11], [11, 14]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_2() {
+        assert_eq!(
+            Backend::t3_t1_sub([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [4, 5], 2),
+            [[[2, 3], [5, 5]], [[8, 9], [11, 13]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_sub() {
+        assert_eq!(
+            Backend::t3_t2_sub([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [[2, 3], [4, 5]]),
+            [[[4, 5], [5, 5]], [[10, 11], [11, 13]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_sub() {
+        assert_eq!(
+            Backend::t3_t3_sub(
+                [[[6, 8], [9, 10]], [[12, 14], [15, 18]]],
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+            ),
+            [[[5, 6], [6, 6]], [[7, 8], [8, 10]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_mul() {
+        assert_eq!(
+            Backend::t3_t0_mul([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], 2),
+            [[[12, 16], [18, 20]], [[24, 28], [30, 36]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t3_t1_mul([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [2, 3], 0),
+            [[[12, 16], [18, 20]], [[36, 42], [45, 54]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t3_t1_mul([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [3, 4], 1),
+            [[[18, 24], [36, 40]], [[36, 42], [60, 72]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_2() {
+        assert_eq!(
+            Backend::t3_t1_mul([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [4, 5], 2),
+            [[[24, 40], [36, 50]], [[48, 70], [60, 90]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_mul() {
+        assert_eq!(
+            Backend::t3_t2_mul([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [[2, 3], [4, 5]]),
+            [[[12, 24], [36, 50]], [[24, 42], [60, 90]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_mul() {
+        assert_eq!(
+            Backend::t3_t3_mul(
+                [[[6, 8], [9, 10]], [[12, 14], [15, 18]]],
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+            ),
+            [[[6, 16], [27, 40]], [[60, 84], [105, 144]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_div() {
+        assert_eq!(
+            Backend::t3_t0_div([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], 2),
+            [[[3, 4], [4, 5]], [[6, 7], [7, 9]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_0() {
+        assert_eq!(
+            Backend::t3_t1_div([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [2, 3], 0),
+            [[[3, 4], [4, 5]], [[4, 4], [5, 6]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_1() {
+        assert_eq!(
+            Backend::t3_t1_div([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [3, 4], 1),
+            [[[2, 2], [2, 2]], [[4, 4], [3, 4]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_2() {
+        assert_eq!(
+            Backend::t3_t1_div([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [4, 5], 2),
+            [[[1, 1], [2, 2]], [[3, 2], [3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_div() {
+        assert_eq!(
+            Backend::t3_t2_div([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [[2, 3], [4, 5]]),
+            [[[3, 2], [2, 2]], [[6, 4], [3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_div() {
+        assert_eq!(
+            Backend::t3_t3_div(
+                [[[6, 8], [9, 10]], [[12, 14], [15, 18]]],
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+            ),
+            [[[6, 4], [3, 2]], [[2, 2], [2, 2]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_rem() {
+        assert_eq!(
+            Backend::t3_t0_rem([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], 2),
+            [[[0, 0], [1, 0]], [[0, 0], [1, 0]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t3_t1_rem([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [2, 3], 0),
+            [[[0, 0], [1, 0]], [[0, 2], [0, 0]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t3_t1_rem([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [3, 4], 1),
+            [[[0, 2], [1, 2]], [[0, 2], [3, 2]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_2() {
+        assert_eq!(
+            Backend::t3_t1_rem([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [4, 5], 2),
+            [[[2, 3], [1, 0]], [[0, 4], [3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_rem() {
+        assert_eq!(
+            Backend::t3_t2_rem([[[6, 8], [9, 10]], [[12, 14], [15, 18]]], [[2, 3], [4, 5]]),
+            [[[0, 2], [1, 0]], [[0, 2], [3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_rem() {
+        assert_eq!(
+            Backend::t3_t3_rem(
+                [[[6, 8], [9, 10]], [[12, 14], [15, 18]]],
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]
+            ),
+            [[[0, 0], [0, 2]], [[2, 2], [1, 2]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_neg() {
+        assert_eq!(Backend::t0_neg(3), -3);
+    }
+
+    #[test]
+    fn test_t1_neg() {
+        assert_eq!(Backend::t1_neg([1, -2, 3]), [-1, 2, -3]);
+    }
+
+    #[test]
+    fn test_t2_neg() {
+        assert_eq!(Backend::t2_neg([[1, -2], [3, 0]]), [[-1, 2], [-3, 0]]);
+    }
+
+    #[test]
+    fn test_t3_neg() {
+        assert_eq!(
+            Backend::t3_neg([[[1, -2]], [[3, 0]]]),
+            [[[-1, 2]], [[-3, 0]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_map() {
+        assert_eq!(Backend::t0_map(3, |a| a * 2), 6);
+    }
+
+    #[test]
+    fn test_t1_map() {
+        assert_eq!(Backend::t1_map([1, -2, 3], |a| a * a), [1, 4, 9]);
+    }
+
+    #[test]
+    fn test_t2_map() {
+        assert_eq!(
+            Backend::t2_map([[1, -2], [3, 0]], |a| a + 1),
+            [[2, -1], [4, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t3_map() {
+        assert_eq!(
+            Backend::t3_map([[[1, -2]], [[3, 0]]], |a| a - 1),
+            [[[0, -3]], [[2, -1]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_zip_with() {
+        assert_eq!(Backend::t0_zip_with(3, 4, |a, b| a * b), 12);
+    }
+
+    #[test]
+    fn test_t1_zip_with() {
+        assert_eq!(
+            Backend::t1_zip_with([1, -2, 3], [0, 5, 2], |a, b| a.max(b)),
+            [1, 5, 3]
+        );
+    }
+
+    #[test]
+    fn test_t2_zip_with() {
+        assert_eq!(
+            Backend::t2_zip_with([[1, 2], [3, 4]], [[5, 6], [7, 8]], |a, b| a * 10 + b),
+            [[15, 26], [37, 48]]
+        );
+    }
+
+    #[test]
+    fn test_t3_zip_with() {
+        assert_eq!(
+            Backend::t3_zip_with([[[1, -2]], [[3, 0]]], [[[1, 1]], [[1, 1]]], |a, b| a - b),
+            [[[0, -3]], [[2, -1]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_fold() {
+        assert_eq!(Backend::t0_fold(3, 1, |acc, a| acc + a), 4);
+    }
+
+    #[test]
+    fn test_t1_fold() {
+        assert_eq!(Backend::t1_fold([1, 2, 3], 0, |acc, a| acc * 10 + a), 123);
+    }
+
+    #[test]
+    fn test_t2_fold() {
+        assert_eq!(
+            Backend::t2_fold([[1, 2], [3, 4]], 0, |acc, a| acc * 10 + a),
+            1234
+        );
+    }
+
+    #[test]
+    fn test_t3_fold() {
+        assert_eq!(
+            Backend::t3_fold([[[1, 2]], [[3, 4]]], Vec::new(), |mut acc, a| {
+                acc.push(a);
+                acc
+            }),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_t1_sum() {
+        assert_eq!(Backend::t1_sum([1, 2, 3]), 6);
+    }
+
+    #[test]
+    fn test_t1_prod() {
+        assert_eq!(Backend::t1_prod([2, 3, 4]), 24);
+    }
+
+    #[test]
+    fn test_t1_min() {
+        assert_eq!(Backend::t1_min([3, 1, 2]), 1);
+    }
+
+    #[test]
+    fn test_t1_max() {
+        assert_eq!(Backend::t1_max([3, 1, 2]), 3);
+    }
+
+    #[test]
+    fn test_t1_mean() {
+        assert_eq!(Backend::t1_mean([1.0, 2.0, 6.0]), 3.0);
+    }
+
+    #[test]
+    fn test_t1_argmin() {
+        assert_eq!(Backend::t1_argmin([3, 1, 2, 1]), 1);
+    }
+
+    #[test]
+    fn test_t1_argmax() {
+        assert_eq!(Backend::t1_argmax([3, 1, 3, 2]), 0);
+    }
+
+    #[test]
+    fn test_t2_sum_axis() {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(Backend::t2_sum_axis(a, 0), [5, 7, 9]);
+        assert_eq!(Backend::t2_sum_axis(a, 1), [6, 15]);
+    }
+
+    #[test]
+    fn test_t2_prod_axis() {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(Backend::t2_prod_axis(a, 0), [4, 10, 18]);
+        assert_eq!(Backend::t2_prod_axis(a, 1), [6, 120]);
+    }
+
+    #[test]
+    fn test_t2_min_axis() {
+        let a = [[1, 5, 3], [4, 2, 6]];
+        assert_eq!(Backend::t2_min_axis(a, 0), [1, 2, 3]);
+        assert_eq!(Backend::t2_min_axis(a, 1), [1, 2]);
+    }
+
+    #[test]
+    fn test_t2_max_axis() {
+        let a = [[1, 5, 3], [4, 2, 6]];
+        assert_eq!(Backend::t2_max_axis(a, 0), [4, 5, 6]);
+        assert_eq!(Backend::t2_max_axis(a, 1), [5, 6]);
+    }
+
+    #[test]
+    fn test_t2_mean_axis() {
+        let a = [[1.0, 2.0, 3.0], [5.0, 6.0, 7.0]];
+        assert_eq!(Backend::t2_mean_axis(a, 0), [3.0, 4.0, 5.0]);
+        assert_eq!(Backend::t2_mean_axis(a, 1), [2.0, 6.0]);
+    }
+
+    #[test]
+    fn test_t2_argmin_axis() {
+        let a = [[1, 5, 3], [4, 2, 6]];
+        assert_eq!(Backend::t2_argmin_axis(a, 0), [0, 1, 0]);
+        assert_eq!(Backend::t2_argmin_axis(a, 1), [0, 1]);
+    }
+
+    #[test]
+    fn test_t2_argmax_axis() {
+        let a = [[1, 5, 3], [4, 2, 6]];
+        assert_eq!(Backend::t2_argmax_axis(a, 0), [1, 0, 1]);
+        assert_eq!(Backend::t2_argmax_axis(a, 1), [1, 2]);
+    }
+
+    #[test]
+    fn test_t3_sum_axis() {
+        let a = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(Backend::t3_sum_axis(a, 0), [[6, 8], [10, 12]]);
+        assert_eq!(Backend::t3_sum_axis(a, 1), [[4, 6], [12, 14]]);
+        assert_eq!(Backend::t3_sum_axis(a, 2), [[3, 7], [11, 15]]);
+    }
+
+    #[test]
+    fn test_t3_prod_axis() {
+        let a = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(Backend::t3_prod_axis(a, 0), [[5, 12], [21, 32]]);
+        assert_eq!(Backend::t3_prod_axis(a, 1), [[3, 8], [35, 48]]);
+        assert_eq!(Backend::t3_prod_axis(a, 2), [[2, 12], [30, 56]]);
+    }
+
+    #[test]
+    fn test_t3_min_axis() {
+        let a = [[[1, 8], [3, 4]], [[5, 6], [2, 7]]];
+        assert_eq!(Backend::t3_min_axis(a, 0), [[1, 6], [2, 4]]);
+        assert_eq!(Backend::t3_min_axis(a, 1), [[1, 4], [2, 6]]);
+        assert_eq!(Backend::t3_min_axis(a, 2), [[1, 3], [5, 2]]);
+    }
+
+    #[test]
+    fn test_t3_max_axis() {
+        let a = [[[1, 8], [3, 4]], [[5, 6], [2, 7]]];
+        assert_eq!(Backend::t3_max_axis(a, 0), [[5, 8], [3, 7]]);
+        assert_eq!(Backend::t3_max_axis(a, 1), [[3, 8], [5, 7]]);
+        assert_eq!(Backend::t3_max_axis(a, 2), [[8, 4], [6, 7]]);
+    }
+
+    #[test]
+    fn test_t3_mean_axis() {
+        let a = [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        assert_eq!(Backend::t3_mean_axis(a, 0), [[3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(Backend::t3_mean_axis(a, 1), [[2.0, 3.0], [6.0, 7.0]]);
+        assert_eq!(Backend::t3_mean_axis(a, 2), [[1.5, 3.5], [5.5, 7.5]]);
+    }
+
+    #[test]
+    fn test_t3_argmin_axis() {
+        let a = [[[1, 8], [3, 4]], [[5, 6], [2, 7]]];
+        assert_eq!(Backend::t3_argmin_axis(a, 0), [[0, 1], [1, 0]]);
+        assert_eq!(Backend::t3_argmin_axis(a, 1), [[0, 1], [1, 0]]);
+        assert_eq!(Backend::t3_argmin_axis(a, 2), [[0, 0], [0, 0]]);
+    }
+
+    #[test]
+    fn test_t3_argmax_axis() {
+        let a = [[[1, 8], [3, 4]], [[5, 6], [2, 7]]];
+        assert_eq!(Backend::t3_argmax_axis(a, 0), [[1, 0], [0, 1]]);
+        assert_eq!(Backend::t3_argmax_axis(a, 1), [[1, 0], [0, 1]]);
+        assert_eq!(Backend::t3_argmax_axis(a, 2), [[1, 1], [1, 1]]);
+    }
+
+    #[test]
+    fn test_t1_t1_dot() {
+        assert_eq!(Backend::t1_t1_dot([1, 2, 3], [4, 5, 6]), 32);
+    }
+
+    #[test]
+    fn test_t2_t1_matvec() {
+        assert_eq!(
+            Backend::t2_t1_matvec([[1, 2, 3], [4, 5, 6]], [2, 3, 4]),
+            [20, 47]
+        );
+    }
+
+    #[test]
+    fn test_t1_t2_vecmat() {
+        assert_eq!(
+            Backend::t1_t2_vecmat([2, 3], [[1, 2, 3], [4, 5, 6]]),
+            [14, 19, 24]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_matmul() {
+        assert_eq!(
+            Backend::t2_t2_matmul([[1, 2, 3], [4, 5, 6]], [[2, 3], [4, 5], [6, 7]]),
+            [[28, 34], [64, 79]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_matmul() {
+        assert_eq!(
+            Backend::t3_t2_matmul([[[1, 2], [3, 4]], [[5, 6], [7, 8]]], [[1, 0, 2], [0, 1, 3]]),
+            [[[1, 2, 8], [3, 4, 18]], [[5, 6, 28], [7, 8, 38]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_bmm() {
+        assert_eq!(
+            Backend::t3_t3_bmm(
+                [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+                [[[1], [1]], [[2], [-1]]]
+            ),
+            [[[3], [7]], [[4], [6]]]
+        );
+    }
+}
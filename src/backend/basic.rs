@@ -1,6 +1,7 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use crate::backend::Backend as BackendTrait;
+use crate::backend::{argmax, argmin, gemm, max, mean, min, prod, sum, Backend as BackendTrait};
+use crate::num::{One, Zero};
 
 #[derive(Clone, Debug)]
 pub struct Backend {}
@@ -9,112 +10,505 @@ impl<T: Clone> BackendTrait<T> for Backend {
     type Index = usize;
     type Dimension = usize;
     type T0Repr = T;
-    type T1Repr = Vec<T>;
-    type T2Repr = Vec<Vec<T>>;
-    type T3Repr = Vec<Vec<Vec<T>>>;
+    type T1Repr<const D0: usize> = Vec<T>;
+    type T2Repr<const D0: usize, const D1: usize> = Vec<Vec<T>>;
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize> = Vec<Vec<Vec<T>>>;
+
+    fn t0_from_value(v: T) -> Self::T0Repr {
+        v
+    }
+
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0> {
+        a.into()
+    }
+
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1> {
+        a.into_iter().map(Vec::from).collect()
+    }
+
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a.into_iter()
+            .map(|a| a.into_iter().map(Vec::from).collect())
+            .collect()
+    }
+
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0> {
+        v
+    }
+
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1> {
+        assert_eq!(v.len(), D0 * D1);
+        let mut v = v.into_iter();
+        (0..D0).map(|_| v.by_ref().take(D1).collect()).collect()
+    }
+
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        assert_eq!(v.len(), D0 * D1 * D2);
+        let mut v = v.into_iter();
+        (0..D0)
+            .map(|_| (0..D1).map(|_| v.by_ref().take(D2).collect()).collect())
+            .collect()
+    }
+
+    fn t0_into_value(a: Self::T0Repr) -> T {
+        a
+    }
+
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T> {
+        a
+    }
+
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T> {
+        a.into_iter().flatten().collect()
+    }
+
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T> {
+        a.into_iter().flatten().flatten().collect()
+    }
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t0_get(a: &Self::T0Repr) -> &T {
+        a
+    }
+
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T {
+        a
+    }
+
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        &a[i]
+    }
+
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        &mut a[i]
+    }
+
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        &a[i][j]
+    }
+
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        &mut a[i][j]
+    }
+
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        &a[i][j][k]
+    }
+
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        &mut a[i][j][k]
+    }
+
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        a.get_unchecked(i)
+    }
+
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        a.get_unchecked_mut(i)
+    }
+
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        a.get_unchecked(i).get_unchecked(j)
+    }
+
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        a.get_unchecked_mut(i).get_unchecked_mut(j)
+    }
+
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        a.get_unchecked(i).get_unchecked(j).get_unchecked(k)
+    }
+
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        a.get_unchecked_mut(i)
+            .get_unchecked_mut(j)
+            .get_unchecked_mut(k)
+    }
 
     fn t0_zero() -> Self::T0Repr
     where
-        T: From<u8>,
+        T: Zero,
     {
-        0.into()
+        T::zero()
     }
 
     fn t0_one() -> Self::T0Repr
     where
-        T: From<u8>,
+        T: One,
     {
-        1.into()
+        T::one()
     }
 
-    fn t1_zeros(d0: usize) -> Self::T1Repr
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
-        vec![0.into(); d0]
+        vec![T::zero(); D0]
     }
 
-    fn t1_ones(d0: usize) -> Self::T1Repr
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
     where
-        T: From<u8> + Copy,
+        T: One + Copy,
     {
-        vec![1.into(); d0]
+        vec![T::one(); D0]
     }
 
-    fn t2_zeros(d0: usize, d1: usize) -> Self::T2Repr
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
-        vec![vec![0.into(); d1]; d0]
+        vec![vec![T::zero(); D1]; D0]
     }
 
-    fn t2_ones(d0: usize, d1: usize) -> Self::T2Repr
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
     where
-        T: From<u8> + Copy,
+        T: One + Copy,
     {
-        vec![vec![1.into(); d1]; d0]
+        vec![vec![T::one(); D1]; D0]
     }
 
-    fn t3_zeros(d0: usize, d1: usize, d2: usize) -> Self::T3Repr
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
-        vec![vec![vec![0.into(); d2]; d1]; d0]
+        vec![vec![vec![T::zero(); D2]; D1]; D0]
     }
 
-    fn t3_ones(d0: Self::Index, d1: Self::Index, d2: Self::Index) -> Self::T3Repr
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
     where
-        T: From<u8> + Copy,
+        T: One + Copy,
     {
-        vec![vec![vec![1.into(); d2]; d1]; d0]
+        vec![vec![vec![T::one(); D2]; D1]; D0]
     }
 
-    fn t2_identity(d: usize) -> Self::T2Repr
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
     where
-        T: From<u8> + Copy,
+        T: Zero + One + Copy,
     {
-        let mut matrix = Self::t2_zeros(d, d);
-        for i in 0..d {
-            matrix[i][i] = 1.into();
+        let mut matrix = Self::t2_zeros::<D0, D0>();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = T::one();
         }
         matrix
     }
 
-    fn t2_transpose(a: Self::T2Repr) -> Self::T2Repr
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
-        let mut result = Self::t2_zeros(a[0].len(), a.len());
-        for i in 0..a.len() {
-            for j in 0..a[0].len() {
+        let mut result = Self::t2_zeros::<D1, D0>();
+        for i in 0..D0 {
+            for j in 0..D1 {
                 result[j][i] = a[i][j];
             }
         }
         result
     }
 
-    fn t3_permute(
-        a: Self::T3Repr,
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (Self::Dimension, Self::Dimension),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy,
+    {
+        match p {
+            (0, 1) => a,
+            (1, 0) => Self::t2_transpose::<D0, D1>(a),
+            _ => panic!("improper permute"),
+        }
+    }
+
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
         p: (Self::Dimension, Self::Dimension, Self::Dimension),
-    ) -> Self::T3Repr
+    ) -> Self::T3Repr<RD0, RD1, RD2>
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
-        let d = [a.len(), a[0].len(), a[0][0].len()];
-        let (pd0, pd1, pd2) = (d[p.0], d[p.1], d[p.2]);
-        let mut result = Self::t3_zeros(pd0, pd1, pd2);
-        for i in 0..a.len() {
-            for j in 0..a[0].len() {
-                for k in 0..a[0][0].len() {
+        if p == (0, 1, 2) {
+            return a;
+        }
+
+        let mut result = Self::t3_zeros::<RD0, RD1, RD2>();
+        for (i, a) in a.iter().enumerate() {
+            for (j, a) in a.iter().enumerate() {
+                for (k, a) in a.iter().enumerate() {
                     let idx = [i, j, k];
                     let (pi, pj, pk) = (idx[p.0], idx[p.1], idx[p.2]);
-                    result[pi][pj][pk] = a[i][j][k];
+                    result[pi][pj][pk] = *a;
                 }
             }
         }
         result
     }
 
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        mut a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0> {
+        a.extend(b);
+        a
+    }
+
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        mut a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1> {
+        match axis {
+            0 => {
+                assert_eq!(a[0].len(), b[0].len());
+                a.extend(b);
+                a
+            }
+            1 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| Self::t1_concat::<D1, OD1, RD1>(a, b))
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        mut a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: usize,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        match axis {
+            0 => {
+                assert_eq!(a[0].len(), b[0].len());
+                assert_eq!(a[0][0].len(), b[0][0].len());
+                a.extend(b);
+                a
+            }
+            1 | 2 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| Self::t2_concat::<D1, D2, OD1, OD2, RD1, RD2>(a, b, axis - 1))
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1> {
+        assert!(a.iter().all(|v| v.len() == D1));
+        a.into()
+    }
+
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        assert!(a.iter().all(|m| m.len() == D1 && m[0].len() == D2));
+        a.into()
+    }
+
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        mut a: Self::T1Repr<D0>,
+        at: usize,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>) {
+        let b = a.split_off(at);
+        (a, b)
+    }
+
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        mut a: Self::T2Repr<D0, D1>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>) {
+        match axis {
+            0 => {
+                let b = a.split_off(at);
+                (a, b)
+            }
+            1 => a
+                .into_iter()
+                .map(|a| Self::t1_split::<D1, LD1, RD1>(a, at))
+                .unzip(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        mut a: Self::T3Repr<D0, D1, D2>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>) {
+        match axis {
+            0 => {
+                let b = a.split_off(at);
+                (a, b)
+            }
+            1 | 2 => a
+                .into_iter()
+                .map(|a| Self::t2_split::<D1, D2, LD1, LD2, RD1, RD2>(a, at, axis - 1))
+                .unzip(),
+            _ => unreachable!(),
+        }
+    }
+
     fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
     where
         T: Add<Output = T>,
@@ -122,25 +516,24 @@ impl<T: Clone> BackendTrait<T> for Backend {
         a + b
     }
 
-    fn t1_t0_add(a: Self::T1Repr, b: Self::T0Repr) -> Self::T1Repr
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
     where
         T: Add<Output = T> + Copy,
     {
         a.into_iter().map(|a| a + b).collect()
     }
 
-    fn t1_t1_add(a: Self::T1Repr, b: Self::T1Repr) -> Self::T1Repr
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
     where
         T: Add<Output = T>,
     {
-        assert_eq!(a.len(), b.len());
-        a.into_iter()
-            .zip(b.into_iter())
-            .map(|(a, b)| a + b)
-            .collect()
+        Self::t1_zip_with::<D0>(a, b, T::add)
     }
 
-    fn t2_t0_add(a: Self::T2Repr, b: Self::T0Repr) -> Self::T2Repr
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
     where
         T: Add<Output = T> + Copy,
     {
@@ -149,7 +542,11 @@ impl<T: Clone> BackendTrait<T> for Backend {
             .collect()
     }
 
-    fn t2_t1_add(a: Self::T2Repr, b: Self::T1Repr, along: Self::Dimension) -> Self::T2Repr
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
     where
         T: Add<Output = T> + Copy,
     {
@@ -157,7 +554,7 @@ impl<T: Clone> BackendTrait<T> for Backend {
             0 => {
                 assert_eq!(a.len(), b.len());
                 a.into_iter()
-                    .zip(b.into_iter())
+                    .zip(b)
                     .map(|(a, b)| a.into_iter().map(|a| a + b).collect())
                     .collect()
             }
@@ -171,131 +568,1487 @@ impl<T: Clone> BackendTrait<T> for Backend {
         }
     }
 
-    fn t2_t2_add(a: Self::T2Repr, b: Self::T2Repr) -> Self::T2Repr
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
     where
         T: Add<Output = T>,
     {
-        a.into_iter()
-            .zip(b.into_iter())
-            .map(|(a, b)| {
-                a.into_iter()
-                    .zip(b.into_iter())
-                    .map(|(a, b)| a + b)
-                    .collect()
-            })
-            .collect()
+        Self::t2_zip_with::<D0, D1>(a, b, T::add)
     }
 
-    fn t1_t1_dot(a: Self::T1Repr, b: Self::T1Repr) -> Self::T0Repr
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
     where
-        T: Add<Output = T> + Mul<Output = T> + From<u8>,
+        T: Add<Output = T> + Copy,
     {
         a.into_iter()
-            .zip(b.into_iter())
-            .fold(0.into(), |acc, (a, b)| acc + a * b)
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| a + b).collect())
+                    .collect()
+            })
+            .collect()
     }
 
-    fn t2_t2_matmul(a: Self::T2Repr, b: Self::T2Repr) -> Self::T2Repr
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
     where
-        T: Add<Output = T> + Mul<Output = T> + From<u8> + Copy,
+        T: Add<Output = T> + Copy,
     {
-        let mut result = Self::t2_zeros(a.len(), b[0].len());
-        for i in 0..a.len() {
-            for j in 0..b[0].len() {
-                for k in 0..b.len() {
-                    result[i][j] = result[i][j] + a[i][k] * b[k][j];
-                }
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().map(|a| a + b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .zip(b.iter())
+                            .map(|(a, b)| a.into_iter().map(|a| a + *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            2 => {
+                assert_eq!(a[0][0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a + *b).collect())
+                            .collect()
+                    })
+                    .collect()
             }
+            _ => unreachable!(),
         }
-        result
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_t0_zero() {
-        let zero: u8 = Backend::t0_zero();
-        assert_eq!(zero, 0);
     }
 
-    #[test]
-    fn test_t0_one() {
-        let one: u8 = Backend::t0_one();
-        assert_eq!(one, 1);
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        assert_eq!(a[0][0].len(), b[0].len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.into_iter().zip(b.iter()).map(|(a, b)| a + *b).collect())
+                    .collect()
+            })
+            .collect()
     }
 
-    #[test]
-    fn test_t1_zeros() {
-        let zeros: Vec<u8> = Backend::t1_zeros(3);
-        assert_eq!(zeros, vec![0, 0, 0]);
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T>,
+    {
+        Self::t3_zip_with::<D0, D1, D2>(a, b, T::add)
     }
 
-    #[test]
-    fn test_t1_ones() {
-        let ones: Vec<u8> = Backend::t1_ones(3);
-        assert_eq!(ones, vec![1, 1, 1]);
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>,
+    {
+        a - b
     }
 
-    #[test]
-    fn test_t2_zeros() {
-        let zeros: Vec<Vec<u8>> = Backend::t2_zeros(2, 3);
-        assert_eq!(zeros, vec![vec![0, 0, 0], vec![0, 0, 0]]);
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.into_iter().map(|a| a - b).collect()
     }
 
-    #[test]
-    fn test_t2_ones() {
-        let ones: Vec<Vec<u8>> = Backend::t2_ones(2, 3);
-        assert_eq!(ones, vec![vec![1, 1, 1], vec![1, 1, 1]]);
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>,
+    {
+        assert_eq!(a.len(), b.len());
+        a.into_iter().zip(b).map(|(a, b)| a - b).collect()
     }
 
-    #[test]
-    fn test_t3_zeros() {
-        let zeros: Vec<Vec<Vec<u8>>> = Backend::t3_zeros(2, 3, 4);
-        assert_eq!(
-            zeros,
-            vec![
-                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]],
-                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]]
-            ]
-        );
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| a.into_iter().map(|a| a - b).collect())
+            .collect()
     }
 
-    #[test]
-    fn test_t3_ones() {
-        let ones: Vec<Vec<Vec<u8>>> = Backend::t3_ones(2, 3, 4);
-        assert_eq!(
-            ones,
-            vec![
-                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]],
-                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]]
-            ]
-        );
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().map(|a| a - b).collect())
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a - *b).collect())
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
     }
 
-    #[test]
-    fn test_t2_identity() {
-        let eye: Vec<Vec<u8>> = Backend::t2_identity(3);
-        assert_eq!(eye, vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a - b).collect())
+            .collect()
     }
 
-    #[test]
-    fn test_t2_transpose() {
-        assert_eq!(
-            Backend::t2_transpose(vec![vec![1, 2, 3], vec![4, 5, 6]]),
-            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
-        );
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| a - b).collect())
+                    .collect()
+            })
+            .collect()
     }
 
-    #[test]
-    fn test_t3_permute() {
-        let input = vec![
-            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
-            vec![vec![10, 11, 12], vec![13, 14, 15], vec![16, 17, 18]],
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().map(|a| a - b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .zip(b.iter())
+                            .map(|(a, b)| a.into_iter().map(|a| a - *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            2 => {
+                assert_eq!(a[0][0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a - *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        assert_eq!(a[0][0].len(), b[0].len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.into_iter().zip(b.iter()).map(|(a, b)| a - *b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a - b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>,
+    {
+        a * b
+    }
+
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.into_iter().map(|a| a * b).collect()
+    }
+
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>,
+    {
+        assert_eq!(a.len(), b.len());
+        a.into_iter().zip(b).map(|(a, b)| a * b).collect()
+    }
+
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| a.into_iter().map(|a| a * b).collect())
+            .collect()
+    }
+
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().map(|a| a * b).collect())
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a * *b).collect())
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a * b).collect())
+            .collect()
+    }
+
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| a * b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().map(|a| a * b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .zip(b.iter())
+                            .map(|(a, b)| a.into_iter().map(|a| a * *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            2 => {
+                assert_eq!(a[0][0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a * *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        assert_eq!(a[0][0].len(), b[0].len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.into_iter().zip(b.iter()).map(|(a, b)| a * *b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a * b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>,
+    {
+        a / b
+    }
+
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.into_iter().map(|a| a / b).collect()
+    }
+
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>,
+    {
+        assert_eq!(a.len(), b.len());
+        a.into_iter().zip(b).map(|(a, b)| a / b).collect()
+    }
+
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| a.into_iter().map(|a| a / b).collect())
+            .collect()
+    }
+
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().map(|a| a / b).collect())
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a / *b).collect())
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a / b).collect())
+            .collect()
+    }
+
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| a / b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().map(|a| a / b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .zip(b.iter())
+                            .map(|(a, b)| a.into_iter().map(|a| a / *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            2 => {
+                assert_eq!(a[0][0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a / *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        assert_eq!(a[0][0].len(), b[0].len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.into_iter().zip(b.iter()).map(|(a, b)| a / *b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a / b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>,
+    {
+        a % b
+    }
+
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.into_iter().map(|a| a % b).collect()
+    }
+
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>,
+    {
+        assert_eq!(a.len(), b.len());
+        a.into_iter().zip(b).map(|(a, b)| a % b).collect()
+    }
+
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| a.into_iter().map(|a| a % b).collect())
+            .collect()
+    }
+
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().map(|a| a % b).collect())
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a % *b).collect())
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a % b).collect())
+            .collect()
+    }
+
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| a % b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        match along {
+            0 => {
+                assert_eq!(a.len(), b.len());
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().map(|a| a % b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            1 => {
+                assert_eq!(a[0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .zip(b.iter())
+                            .map(|(a, b)| a.into_iter().map(|a| a % *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            2 => {
+                assert_eq!(a[0][0].len(), b.len());
+                a.into_iter()
+                    .map(|a| {
+                        a.into_iter()
+                            .map(|a| a.into_iter().zip(b.iter()).map(|(a, b)| a % *b).collect())
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        assert_eq!(a[0][0].len(), b[0].len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a.into_iter().zip(b.iter()).map(|(a, b)| a % *b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>,
+    {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| a % b).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Neg<Output = T>,
+    {
+        -a
+    }
+
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>,
+    {
+        a.into_iter().map(|a| -a).collect()
+    }
+
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>,
+    {
+        a.into_iter()
+            .map(|a| a.into_iter().map(|a| -a).collect())
+            .collect()
+    }
+
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>,
+    {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(|a| -a).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr {
+        f(a)
+    }
+
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0> {
+        a.into_iter().map(f).collect()
+    }
+
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        a.into_iter()
+            .map(|a| a.into_iter().map(&f).collect())
+            .collect()
+    }
+
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .map(|a| a.into_iter().map(&f).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr {
+        f(a, b)
+    }
+
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0> {
+        assert_eq!(a.len(), b.len());
+        a.into_iter().zip(b).map(|(a, b)| f(a, b)).collect()
+    }
+
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| f(a, b)).collect())
+            .collect()
+    }
+
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| {
+                a.into_iter()
+                    .zip(b)
+                    .map(|(a, b)| a.into_iter().zip(b).map(|(a, b)| f(a, b)).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn t0_fold<U>(a: Self::T0Repr, init: U, mut f: impl FnMut(U, T) -> U) -> U {
+        f(init, a)
+    }
+
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U {
+        a.into_iter().fold(init, f)
+    }
+
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_iter().flatten().fold(init, f)
+    }
+
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_iter().flatten().flatten().fold(init, f)
+    }
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        sum(&a)
+    }
+
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        prod(&a)
+    }
+
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        min(&a)
+    }
+
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        max(&a)
+    }
+
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        mean(&a)
+    }
+
+    fn t1_argmin<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmin(&a)
+    }
+
+    fn t1_argmax<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmax(&a)
+    }
+
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| sum(&a)).collect()
+    }
+
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| prod(&a)).collect()
+    }
+
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| min(&a)).collect()
+    }
+
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| max(&a)).collect()
+    }
+
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| mean(&a)).collect()
+    }
+
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Vec<usize>
+    where
+        T: PartialOrd + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| argmin(&a)).collect()
+    }
+
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Vec<usize>
+    where
+        T: PartialOrd + Copy,
+    {
+        t2_lanes(a, axis).into_iter().map(|a| argmax(&a)).collect()
+    }
+
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| sum(&a)).collect())
+            .collect()
+    }
+
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| prod(&a)).collect())
+            .collect()
+    }
+
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| min(&a)).collect())
+            .collect()
+    }
+
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| max(&a)).collect())
+            .collect()
+    }
+
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| mean(&a)).collect())
+            .collect()
+    }
+
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Vec<Vec<usize>>
+    where
+        T: PartialOrd + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| argmin(&a)).collect())
+            .collect()
+    }
+
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Vec<Vec<usize>>
+    where
+        T: PartialOrd + Copy,
+    {
+        t3_lanes(a, axis)
+            .into_iter()
+            .map(|a| a.into_iter().map(|a| argmax(&a)).collect())
+            .collect()
+    }
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        a.into_iter()
+            .zip(b)
+            .fold(T::zero(), |acc, (a, b)| acc + a * b)
+    }
+
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a[0].len(), b.len());
+        a.into_iter()
+            .map(|a| {
+                a.into_iter()
+                    .zip(b.iter())
+                    .fold(T::zero(), |acc, (a, b)| acc + a * *b)
+            })
+            .collect()
+    }
+
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.len(), b.len());
+        let mut result = Self::t1_zeros::<D1>();
+        for (a, b) in a.into_iter().zip(b) {
+            for (result, b) in result.iter_mut().zip(b) {
+                *result = *result + a * b;
+            }
+        }
+        result
+    }
+
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let c = gemm::matmul(&a.concat(), &b.concat(), (D0, D1, OD1));
+        (0..D0)
+            .map(|i| c[i * OD1..(i + 1) * OD1].to_vec())
+            .collect()
+    }
+
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        a.into_iter()
+            .map(|a| Self::t2_t2_matmul::<D1, D2, OD1>(a, b.clone()))
+            .collect()
+    }
+
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.len(), b.len());
+        a.into_iter()
+            .zip(b)
+            .map(|(a, b)| Self::t2_t2_matmul::<D1, D2, OD2>(a, b))
+            .collect()
+    }
+}
+
+/// Regroups `a` so that each inner vector holds the elements along `axis`.
+fn t2_lanes<T: Clone>(a: Vec<Vec<T>>, axis: usize) -> Vec<Vec<T>> {
+    match axis {
+        0 => (0..a[0].len())
+            .map(|j| a.iter().map(|a| a[j].clone()).collect())
+            .collect(),
+        1 => a,
+        _ => unreachable!(),
+    }
+}
+
+/// Regroups `a` so that each innermost vector holds the elements along `axis`, keeping the other two axes in order.
+fn t3_lanes<T: Clone>(a: Vec<Vec<Vec<T>>>, axis: usize) -> Vec<Vec<Vec<T>>> {
+    match axis {
+        0 => (0..a[0].len())
+            .map(|j| {
+                (0..a[0][0].len())
+                    .map(|k| a.iter().map(|a| a[j][k].clone()).collect())
+                    .collect()
+            })
+            .collect(),
+        1 => a.into_iter().map(|a| t2_lanes(a, 0)).collect(),
+        2 => a,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t0_from_value() {
+        let value: u8 = Backend::t0_from_value(7);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_t1_from_array() {
+        let a: Vec<u8> = Backend::t1_from_array([1, 2, 3]);
+        assert_eq!(a, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_t2_from_array() {
+        let a: Vec<Vec<u8>> = Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_t3_from_array() {
+        let a: Vec<Vec<Vec<u8>>> = Backend::t3_from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert_eq!(
+            a,
+            vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+        );
+    }
+
+    #[test]
+    fn test_t2_from_vec() {
+        let a: Vec<Vec<u8>> = Backend::t2_from_vec::<3, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn test_t3_from_vec() {
+        let a: Vec<Vec<Vec<u8>>> = Backend::t3_from_vec::<3, 1, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            a,
+            vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]]
+        );
+    }
+
+    #[test]
+    fn test_t2_into_vec() {
+        assert_eq!(
+            Backend::t2_into_vec::<2, 3>(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_t3_into_vec() {
+        assert_eq!(
+            Backend::t3_into_vec::<2, 2, 2>(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]]
+            ]),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_t0_get() {
+        let mut a: u8 = 3;
+        assert_eq!(*Backend::t0_get(&a), 3);
+        *Backend::t0_get_mut(&mut a) = 4;
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn test_t1_get() {
+        let mut a = vec![1, 2, 3];
+        assert_eq!(*Backend::t1_get::<3>(&a, 1), 2);
+        *Backend::t1_get_mut::<3>(&mut a, 1) = 5;
+        assert_eq!(a, vec![1, 5, 3]);
+    }
+
+    #[test]
+    fn test_t2_get() {
+        let mut a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(*Backend::t2_get::<2, 3>(&a, 1, 2), 6);
+        *Backend::t2_get_mut::<2, 3>(&mut a, 1, 2) = 7;
+        assert_eq!(a, vec![vec![1, 2, 3], vec![4, 5, 7]]);
+    }
+
+    #[test]
+    fn test_t3_get() {
+        let mut a = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+        assert_eq!(*Backend::t3_get::<2, 2, 2>(&a, 1, 0, 1), 6);
+        *Backend::t3_get_mut::<2, 2, 2>(&mut a, 1, 0, 1) = 9;
+        assert_eq!(
+            a,
+            vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 9], vec![7, 8]]]
+        );
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut a = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+        unsafe {
+            assert_eq!(*Backend::t3_get_unchecked::<2, 2, 2>(&a, 0, 1, 0), 3);
+            *Backend::t3_get_unchecked_mut::<2, 2, 2>(&mut a, 0, 1, 0) = 0;
+            assert_eq!(*Backend::t2_get_unchecked::<2, 2>(&a[1], 1, 1), 8);
+            assert_eq!(*Backend::t1_get_unchecked::<2>(&a[1][0], 0), 5);
+        }
+        assert_eq!(a[0][1][0], 0);
+    }
+
+    #[test]
+    fn test_t0_zero() {
+        let zero: u8 = Backend::t0_zero();
+        assert_eq!(zero, 0);
+    }
+
+    #[test]
+    fn test_t0_one() {
+        let one: u8 = Backend::t0_one();
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn test_t1_zeros() {
+        let zeros: Vec<u8> = Backend::t1_zeros::<3>();
+        assert_eq!(zeros, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_t1_ones() {
+        let ones: Vec<u8> = Backend::t1_ones::<3>();
+        assert_eq!(ones, vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn test_t2_zeros() {
+        let zeros: Vec<Vec<u8>> = Backend::t2_zeros::<2, 3>();
+        assert_eq!(zeros, vec![vec![0, 0, 0], vec![0, 0, 0]]);
+    }
+
+    #[test]
+    fn test_t2_ones() {
+        let ones: Vec<Vec<u8>> = Backend::t2_ones::<2, 3>();
+        assert_eq!(ones, vec![vec![1, 1, 1], vec![1, 1, 1]]);
+    }
+
+    #[test]
+    fn test_t3_zeros() {
+        let zeros: Vec<Vec<Vec<u8>>> = Backend::t3_zeros::<2, 3, 4>();
+        assert_eq!(
+            zeros,
+            vec![
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]],
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_ones() {
+        let ones: Vec<Vec<Vec<u8>>> = Backend::t3_ones::<2, 3, 4>();
+        assert_eq!(
+            ones,
+            vec![
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]],
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t2_identity() {
+        let eye: Vec<Vec<u8>> = Backend::t2_identity::<3>();
+        assert_eq!(eye, vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]);
+    }
+
+    #[test]
+    fn test_t2_transpose() {
+        assert_eq!(
+            Backend::t2_transpose::<2, 3>(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+
+    #[test]
+    fn test_t2_permute() {
+        let a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(Backend::t2_permute::<2, 3, 2, 3>(a.clone(), (0, 1)), a);
+        assert_eq!(
+            Backend::t2_permute::<2, 3, 3, 2>(a, (1, 0)),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+
+    #[test]
+    fn test_t3_permute() {
+        let input = vec![
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+            vec![vec![10, 11, 12], vec![13, 14, 15], vec![16, 17, 18]],
         ];
-        let permuted = Backend::t3_permute(input, (2, 0, 1));
+        let permuted = Backend::t3_permute::<2, 3, 3, 3, 2, 3>(input, (2, 0, 1));
         let expected = vec![
             vec![vec![1, 4, 7], vec![10, 13, 16]],
             vec![vec![2, 5, 8], vec![11, 14, 17]],
@@ -305,71 +2058,1273 @@ mod tests {
     }
 
     #[test]
-    fn test_t0_t0_add() {
-        assert_eq!(Backend::t0_t0_add(1, 2), 3);
+    fn test_t1_concat() {
+        assert_eq!(
+            Backend::t1_concat::<2, 3, 5>(vec![1, 2], vec![3, 4, 5]),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_t2_concat() {
+        let a = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 1, 2, 3, 2>(a.clone(), vec![vec![5, 6]], 0),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 2, 1, 2, 3>(a, vec![vec![5], vec![6]], 1),
+            vec![vec![1, 2, 5], vec![3, 4, 6]]
+        );
+    }
+
+    #[test]
+    fn test_t3_concat() {
+        let a = vec![vec![vec![1, 2]], vec![vec![3, 4]]];
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 1, 1, 2, 3, 1, 2>(a.clone(), vec![vec![vec![5, 6]]], 0),
+            vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]]
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 2, 2, 2, 2>(
+                a.clone(),
+                vec![vec![vec![5, 6]], vec![vec![7, 8]]],
+                1
+            ),
+            vec![vec![vec![1, 2], vec![5, 6]], vec![vec![3, 4], vec![7, 8]]]
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 1, 2, 1, 3>(
+                a,
+                vec![vec![vec![5]], vec![vec![6]]],
+                2
+            ),
+            vec![vec![vec![1, 2, 5]], vec![vec![3, 4, 6]]]
+        );
+    }
+
+    #[test]
+    fn test_t2_stack() {
+        assert_eq!(
+            Backend::t2_stack::<2, 2>([vec![1, 2], vec![3, 4]]),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_t3_stack() {
+        assert_eq!(
+            Backend::t3_stack::<2, 1, 2>([vec![vec![1, 2]], vec![vec![3, 4]]]),
+            vec![vec![vec![1, 2]], vec![vec![3, 4]]]
+        );
+    }
+
+    #[test]
+    fn test_t1_split() {
+        assert_eq!(
+            Backend::t1_split::<5, 2, 3>(vec![1, 2, 3, 4, 5], 2),
+            (vec![1, 2], vec![3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_t2_split() {
+        let a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(
+            Backend::t2_split::<2, 3, 1, 3, 1, 3>(a.clone(), 1, 0),
+            (vec![vec![1, 2, 3]], vec![vec![4, 5, 6]])
+        );
+        assert_eq!(
+            Backend::t2_split::<2, 3, 2, 1, 2, 2>(a, 1, 1),
+            (vec![vec![1], vec![4]], vec![vec![2, 3], vec![5, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_split() {
+        let a = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 1, 2, 2, 1, 2, 2>(a.clone(), 1, 0),
+            (
+                vec![vec![vec![1, 2], vec![3, 4]]],
+                vec![vec![vec![5, 6], vec![7, 8]]]
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 1, 2, 2, 1, 2>(a.clone(), 1, 1),
+            (
+                vec![vec![vec![1, 2]], vec![vec![5, 6]]],
+                vec![vec![vec![3, 4]], vec![vec![7, 8]]]
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 2, 1, 2, 2, 1>(a, 1, 2),
+            (
+                vec![vec![vec![1], vec![3]], vec![vec![5], vec![7]]],
+                vec![vec![vec![2], vec![4]], vec![vec![6], vec![8]]]
+            )
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_add() {
+        assert_eq!(Backend::t0_t0_add(1, 2), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_add() {
+        assert_eq!(Backend::t1_t0_add::<3>(vec![1, 2, 3], 2), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_t1_t1_add() {
+        assert_eq!(
+            Backend::t1_t1_add::<3>(vec![1, 2, 3], vec![4, 5, 6]),
+            vec![5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_add() {
+        assert_eq!(
+            Backend::t2_t0_add::<2, 3>(vec![vec![1, 2, 3], vec![4, 5, 6]], 2),
+            vec![vec![3, 4, 5], vec![6, 7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_0() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 2>(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![2, 3], 0),
+            vec![vec![3, 4, 5], vec![7, 8, 9]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_1() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 3>(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![2, 3, 4], 1),
+            vec![vec![3, 5, 7], vec![6, 8, 10]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_add() {
+        assert_eq!(
+            Backend::t2_t2_add::<2, 3>(
+                vec![vec![1, 2, 3], vec![4, 5, 6]],
+                vec![vec![2, 3, 4], vec![5, 6, 7]]
+            ),
+            vec![vec![3, 5, 7], vec![9, 11, 13]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_sub() {
+        assert_eq!(Backend::t0_t0_sub(9, 3), 6);
+    }
+
+    #[test]
+    fn test_t1_t0_sub() {
+        assert_eq!(Backend::t1_t0_sub::<3>(vec![6, 8, 9], 2), vec![4, 6, 7]);
+    }
+
+    #[test]
+    fn test_t1_t1_sub() {
+        assert_eq!(
+            Backend::t1_t1_sub::<3>(vec![6, 8, 9], vec![4, 3, 2]),
+            vec![2, 5, 7]
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_sub() {
+        assert_eq!(
+            Backend::t2_t0_sub::<2, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], 2),
+            vec![vec![4, 6, 7], vec![8, 10, 13]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 2>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 4], 0),
+            vec![vec![4, 6, 7], vec![6, 8, 11]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 3, 4], 1),
+            vec![vec![4, 5, 5], vec![8, 9, 11]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_sub() {
+        assert_eq!(
+            Backend::t2_t2_sub::<2, 3>(
+                vec![vec![6, 8, 9], vec![10, 12, 15]],
+                vec![vec![4, 3, 2], vec![3, 5, 7]]
+            ),
+            vec![vec![2, 5, 7], vec![7, 7, 8]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_mul() {
+        assert_eq!(Backend::t0_t0_mul(9, 3), 27);
+    }
+
+    #[test]
+    fn test_t1_t0_mul() {
+        assert_eq!(Backend::t1_t0_mul::<3>(vec![6, 8, 9], 2), vec![12, 16, 18]);
+    }
+
+    #[test]
+    fn test_t1_t1_mul() {
+        assert_eq!(
+            Backend::t1_t1_mul::<3>(vec![6, 8, 9], vec![4, 3, 2]),
+            vec![24, 24, 18]
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_mul() {
+        assert_eq!(
+            Backend::t2_t0_mul::<2, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], 2),
+            vec![vec![12, 16, 18], vec![20, 24, 30]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 2>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 4], 0),
+            vec![vec![12, 16, 18], vec![40, 48, 60]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 3, 4], 1),
+            vec![vec![12, 24, 36], vec![20, 36, 60]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_mul() {
+        assert_eq!(
+            Backend::t2_t2_mul::<2, 3>(
+                vec![vec![6, 8, 9], vec![10, 12, 15]],
+                vec![vec![4, 3, 2], vec![3, 5, 7]]
+            ),
+            vec![vec![24, 24, 18], vec![30, 60, 105]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_div() {
+        assert_eq!(Backend::t0_t0_div(9, 3), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_div() {
+        assert_eq!(Backend::t1_t0_div::<3>(vec![6, 8, 9], 2), vec![3, 4, 4]);
+    }
+
+    #[test]
+    fn test_t1_t1_div() {
+        assert_eq!(
+            Backend::t1_t1_div::<3>(vec![6, 8, 9], vec![4, 3, 2]),
+            vec![1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_div() {
+        assert_eq!(
+            Backend::t2_t0_div::<2, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], 2),
+            vec![vec![3, 4, 4], vec![5, 6, 7]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_0() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 2>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 4], 0),
+            vec![vec![3, 4, 4], vec![2, 3, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_1() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 3, 4], 1),
+            vec![vec![3, 2, 2], vec![5, 4, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_div() {
+        assert_eq!(
+            Backend::t2_t2_div::<2, 3>(
+                vec![vec![6, 8, 9], vec![10, 12, 15]],
+                vec![vec![4, 3, 2], vec![3, 5, 7]]
+            ),
+            vec![vec![1, 2, 4], vec![3, 2, 2]]
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_rem() {
+        assert_eq!(Backend::t0_t0_rem(9, 3), 0);
+    }
+
+    #[test]
+    fn test_t1_t0_rem() {
+        assert_eq!(Backend::t1_t0_rem::<3>(vec![6, 8, 9], 2), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_t1_t1_rem() {
+        assert_eq!(
+            Backend::t1_t1_rem::<3>(vec![6, 8, 9], vec![4, 3, 2]),
+            vec![2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_rem() {
+        assert_eq!(
+            Backend::t2_t0_rem::<2, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], 2),
+            vec![vec![0, 0, 1], vec![0, 0, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 2>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 4], 0),
+            vec![vec![0, 0, 1], vec![2, 0, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 3>(vec![vec![6, 8, 9], vec![10, 12, 15]], vec![2, 3, 4], 1),
+            vec![vec![0, 2, 1], vec![0, 0, 3]]
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_rem() {
+        assert_eq!(
+            Backend::t2_t2_rem::<2, 3>(
+                vec![vec![6, 8, 9], vec![10, 12, 15]],
+                vec![vec![4, 3, 2], vec![3, 5, 7]]
+            ),
+            vec![vec![2, 2, 1], vec![1, 2, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_add() {
+        assert_eq!(
+            Backend::t3_t0_add::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                2
+            ),
+            vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![14, 16], vec![17, 20]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_0() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![2, 3],
+                0
+            ),
+            vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![15, 17], vec![18, 21]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_1() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![3, 4],
+                1
+            ),
+            vec![
+                vec![vec![9, 11], vec![13, 14]],
+                vec![vec![15, 17], vec![19, 22]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_2() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![4, 5],
+                2
+            ),
+            vec![
+                vec![vec![10, 13], vec![13, 15]],
+                vec![vec![16, 19], vec![19, 23]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_add() {
+        assert_eq!(
+            Backend::t3_t2_add::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![2, 3], vec![4, 5]]
+            ),
+            vec![
+                vec![vec![8, 11], vec![13, 15]],
+                vec![vec![14, 17], vec![19, 23]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_add() {
+        assert_eq!(
+            Backend::t3_t3_add::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+            ),
+            vec![
+                vec![vec![7, 10], vec![12, 14]],
+                vec![vec![17, 20], vec![22, 26]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_sub() {
+        assert_eq!(
+            Backend::t3_t0_sub::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                2
+            ),
+            vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![10, 12], vec![13, 16]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![2, 3],
+                0
+            ),
+            vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![9, 11], vec![12, 15]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![3, 4],
+                1
+            ),
+            vec![
+                vec![vec![3, 5], vec![5, 6]],
+                vec![vec![9, 11], vec![11, 14]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_2() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![4, 5],
+                2
+            ),
+            vec![vec![vec![2, 3], vec![5, 5]], vec![vec![8, 9], vec![11, 13]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_sub() {
+        assert_eq!(
+            Backend::t3_t2_sub::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![2, 3], vec![4, 5]]
+            ),
+            vec![
+                vec![vec![4, 5], vec![5, 5]],
+                vec![vec![10, 11], vec![11, 13]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_sub() {
+        assert_eq!(
+            Backend::t3_t3_sub::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+            ),
+            vec![vec![vec![5, 6], vec![6, 6]], vec![vec![7, 8], vec![8, 10]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_mul() {
+        assert_eq!(
+            Backend::t3_t0_mul::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                2
+            ),
+            vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![24, 28], vec![30, 36]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![2, 3],
+                0
+            ),
+            vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![36, 42], vec![45, 54]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![3, 4],
+                1
+            ),
+            vec![
+                vec![vec![18, 24], vec![36, 40]],
+                vec![vec![36, 42], vec![60, 72]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_2() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![4, 5],
+                2
+            ),
+            vec![
+                vec![vec![24, 40], vec![36, 50]],
+                vec![vec![48, 70], vec![60, 90]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_mul() {
+        assert_eq!(
+            Backend::t3_t2_mul::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![2, 3], vec![4, 5]]
+            ),
+            vec![
+                vec![vec![12, 24], vec![36, 50]],
+                vec![vec![24, 42], vec![60, 90]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_mul() {
+        assert_eq!(
+            Backend::t3_t3_mul::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+            ),
+            vec![
+                vec![vec![6, 16], vec![27, 40]],
+                vec![vec![60, 84], vec![105, 144]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_div() {
+        assert_eq!(
+            Backend::t3_t0_div::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                2
+            ),
+            vec![vec![vec![3, 4], vec![4, 5]], vec![vec![6, 7], vec![7, 9]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_0() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![2, 3],
+                0
+            ),
+            vec![vec![vec![3, 4], vec![4, 5]], vec![vec![4, 4], vec![5, 6]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_1() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![3, 4],
+                1
+            ),
+            vec![vec![vec![2, 2], vec![2, 2]], vec![vec![4, 4], vec![3, 4]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_2() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![4, 5],
+                2
+            ),
+            vec![vec![vec![1, 1], vec![2, 2]], vec![vec![3, 2], vec![3, 3]]]
+        );
     }
 
     #[test]
-    fn test_t1_t0_add() {
-        assert_eq!(Backend::t1_t0_add(vec![1, 2, 3], 2), vec![3, 4, 5]);
+    fn test_t3_t2_div() {
+        assert_eq!(
+            Backend::t3_t2_div::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![2, 3], vec![4, 5]]
+            ),
+            vec![vec![vec![3, 2], vec![2, 2]], vec![vec![6, 4], vec![3, 3]]]
+        );
     }
 
     #[test]
-    fn test_t1_t1_add() {
+    fn test_t3_t3_div() {
         assert_eq!(
-            Backend::t1_t1_add(vec![1, 2, 3], vec![4, 5, 6]),
-            vec![5, 7, 9]
+            Backend::t3_t3_div::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+            ),
+            vec![vec![vec![6, 4], vec![3, 2]], vec![vec![2, 2], vec![2, 2]]]
         );
     }
 
     #[test]
-    fn test_t2_t0_add() {
+    fn test_t3_t0_rem() {
         assert_eq!(
-            Backend::t2_t0_add(vec![vec![1, 2, 3], vec![4, 5, 6]], 2),
-            vec![vec![3, 4, 5], vec![6, 7, 8]]
+            Backend::t3_t0_rem::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                2
+            ),
+            vec![vec![vec![0, 0], vec![1, 0]], vec![vec![0, 0], vec![1, 0]]]
         );
     }
 
     #[test]
-    fn test_t2_t1_add_along_0() {
+    fn test_t3_t1_rem_along_0() {
         assert_eq!(
-            Backend::t2_t1_add(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![2, 3], 0),
-            vec![vec![3, 4, 5], vec![7, 8, 9]]
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![2, 3],
+                0
+            ),
+            vec![vec![vec![0, 0], vec![1, 0]], vec![vec![0, 2], vec![0, 0]]]
         );
     }
 
     #[test]
-    fn test_t2_t1_add_along_1() {
+    fn test_t3_t1_rem_along_1() {
         assert_eq!(
-            Backend::t2_t1_add(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![2, 3, 4], 1),
-            vec![vec![3, 5, 7], vec![6, 8, 10]]
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![3, 4],
+                1
+            ),
+            vec![vec![vec![0, 2], vec![1, 2]], vec![vec![0, 2], vec![3, 2]]]
         );
     }
 
     #[test]
-    fn test_t2_t2_add() {
+    fn test_t3_t1_rem_along_2() {
         assert_eq!(
-            Backend::t2_t2_add(
-                vec![vec![1, 2, 3], vec![4, 5, 6]],
-                vec![vec![2, 3, 4], vec![5, 6, 7]]
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![4, 5],
+                2
             ),
-            vec![vec![3, 5, 7], vec![9, 11, 13]]
+            vec![vec![vec![2, 3], vec![1, 0]], vec![vec![0, 4], vec![3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_rem() {
+        assert_eq!(
+            Backend::t3_t2_rem::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![2, 3], vec![4, 5]]
+            ),
+            vec![vec![vec![0, 2], vec![1, 0]], vec![vec![0, 2], vec![3, 3]]]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_rem() {
+        assert_eq!(
+            Backend::t3_t3_rem::<2, 2, 2>(
+                vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ],
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]
+            ),
+            vec![vec![vec![0, 0], vec![0, 2]], vec![vec![2, 2], vec![1, 2]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_neg() {
+        assert_eq!(Backend::t0_neg(3), -3);
+    }
+
+    #[test]
+    fn test_t1_neg() {
+        assert_eq!(Backend::t1_neg::<3>(vec![1, -2, 3]), vec![-1, 2, -3]);
+    }
+
+    #[test]
+    fn test_t2_neg() {
+        assert_eq!(
+            Backend::t2_neg::<2, 2>(vec![vec![1, -2], vec![3, 0]]),
+            vec![vec![-1, 2], vec![-3, 0]]
+        );
+    }
+
+    #[test]
+    fn test_t3_neg() {
+        assert_eq!(
+            Backend::t3_neg::<2, 1, 2>(vec![vec![vec![1, -2]], vec![vec![3, 0]]]),
+            vec![vec![vec![-1, 2]], vec![vec![-3, 0]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_map() {
+        assert_eq!(Backend::t0_map(3, |a| a * 2), 6);
+    }
+
+    #[test]
+    fn test_t1_map() {
+        assert_eq!(
+            Backend::t1_map::<3>(vec![1, -2, 3], |a| a * a),
+            vec![1, 4, 9]
+        );
+    }
+
+    #[test]
+    fn test_t2_map() {
+        assert_eq!(
+            Backend::t2_map::<2, 2>(vec![vec![1, -2], vec![3, 0]], |a| a + 1),
+            vec![vec![2, -1], vec![4, 1]]
+        );
+    }
+
+    #[test]
+    fn test_t3_map() {
+        assert_eq!(
+            Backend::t3_map::<2, 1, 2>(vec![vec![vec![1, -2]], vec![vec![3, 0]]], |a| a - 1),
+            vec![vec![vec![0, -3]], vec![vec![2, -1]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_zip_with() {
+        assert_eq!(Backend::t0_zip_with(3, 4, |a, b| a * b), 12);
+    }
+
+    #[test]
+    fn test_t1_zip_with() {
+        assert_eq!(
+            Backend::t1_zip_with::<3>(vec![1, -2, 3], vec![0, 5, 2], |a, b| a.max(b)),
+            vec![1, 5, 3]
+        );
+    }
+
+    #[test]
+    fn test_t2_zip_with() {
+        assert_eq!(
+            Backend::t2_zip_with::<2, 2>(
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]],
+                |a, b| a * 10 + b
+            ),
+            vec![vec![15, 26], vec![37, 48]]
+        );
+    }
+
+    #[test]
+    fn test_t3_zip_with() {
+        assert_eq!(
+            Backend::t3_zip_with::<2, 1, 2>(
+                vec![vec![vec![1, -2]], vec![vec![3, 0]]],
+                vec![vec![vec![1, 1]], vec![vec![1, 1]]],
+                |a, b| a - b
+            ),
+            vec![vec![vec![0, -3]], vec![vec![2, -1]]]
+        );
+    }
+
+    #[test]
+    fn test_t0_fold() {
+        assert_eq!(Backend::t0_fold(3, 1, |acc, a| acc + a), 4);
+    }
+
+    #[test]
+    fn test_t1_fold() {
+        assert_eq!(
+            Backend::t1_fold::<3, _>(vec![1, 2, 3], 0, |acc, a| acc * 10 + a),
+            123
+        );
+    }
+
+    #[test]
+    fn test_t2_fold() {
+        assert_eq!(
+            Backend::t2_fold::<2, 2, _>(vec![vec![1, 2], vec![3, 4]], 0, |acc, a| acc * 10 + a),
+            1234
+        );
+    }
+
+    #[test]
+    fn test_t3_fold() {
+        assert_eq!(
+            Backend::t3_fold::<2, 1, 2, _>(
+                vec![vec![vec![1, 2]], vec![vec![3, 4]]],
+                Vec::new(),
+                |mut acc, a| {
+                    acc.push(a);
+                    acc
+                }
+            ),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_t1_sum() {
+        assert_eq!(Backend::t1_sum::<3>(vec![1, 2, 3]), 6);
+    }
+
+    #[test]
+    fn test_t1_prod() {
+        assert_eq!(Backend::t1_prod::<3>(vec![2, 3, 4]), 24);
+    }
+
+    #[test]
+    fn test_t1_min() {
+        assert_eq!(Backend::t1_min::<3>(vec![3, 1, 2]), 1);
+    }
+
+    #[test]
+    fn test_t1_max() {
+        assert_eq!(Backend::t1_max::<3>(vec![3, 1, 2]), 3);
+    }
+
+    #[test]
+    fn test_t1_mean() {
+        assert_eq!(Backend::t1_mean::<3>(vec![1.0, 2.0, 6.0]), 3.0);
+    }
+
+    #[test]
+    fn test_t1_argmin() {
+        assert_eq!(Backend::t1_argmin::<4>(vec![3, 1, 2, 1]), 1);
+    }
+
+    #[test]
+    fn test_t1_argmax() {
+        assert_eq!(Backend::t1_argmax::<4>(vec![3, 1, 3, 2]), 0);
+    }
+
+    #[test]
+    fn test_t2_sum_axis() {
+        let a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(Backend::t2_sum_axis::<2, 3, 3>(a.clone(), 0), vec![5, 7, 9]);
+        assert_eq!(Backend::t2_sum_axis::<2, 3, 2>(a, 1), vec![6, 15]);
+    }
+
+    #[test]
+    fn test_t2_prod_axis() {
+        let a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(
+            Backend::t2_prod_axis::<2, 3, 3>(a.clone(), 0),
+            vec![4, 10, 18]
+        );
+        assert_eq!(Backend::t2_prod_axis::<2, 3, 2>(a, 1), vec![6, 120]);
+    }
+
+    #[test]
+    fn test_t2_min_axis() {
+        let a = vec![vec![1, 5, 3], vec![4, 2, 6]];
+        assert_eq!(Backend::t2_min_axis::<2, 3, 3>(a.clone(), 0), vec![1, 2, 3]);
+        assert_eq!(Backend::t2_min_axis::<2, 3, 2>(a, 1), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_t2_max_axis() {
+        let a = vec![vec![1, 5, 3], vec![4, 2, 6]];
+        assert_eq!(Backend::t2_max_axis::<2, 3, 3>(a.clone(), 0), vec![4, 5, 6]);
+        assert_eq!(Backend::t2_max_axis::<2, 3, 2>(a, 1), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_t2_mean_axis() {
+        let a = vec![vec![1.0, 2.0, 3.0], vec![5.0, 6.0, 7.0]];
+        assert_eq!(
+            Backend::t2_mean_axis::<2, 3, 3>(a.clone(), 0),
+            vec![3.0, 4.0, 5.0]
+        );
+        assert_eq!(Backend::t2_mean_axis::<2, 3, 2>(a, 1), vec![2.0, 6.0]);
+    }
+
+    #[test]
+    fn test_t2_argmin_axis() {
+        let a = vec![vec![1, 5, 3], vec![4, 2, 6]];
+        assert_eq!(
+            Backend::t2_argmin_axis::<2, 3, 3>(a.clone(), 0),
+            vec![0, 1, 0]
+        );
+        assert_eq!(Backend::t2_argmin_axis::<2, 3, 2>(a, 1), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_t2_argmax_axis() {
+        let a = vec![vec![1, 5, 3], vec![4, 2, 6]];
+        assert_eq!(
+            Backend::t2_argmax_axis::<2, 3, 3>(a.clone(), 0),
+            vec![1, 0, 1]
+        );
+        assert_eq!(Backend::t2_argmax_axis::<2, 3, 2>(a, 1), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_t3_sum_axis() {
+        let a = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![6, 8], vec![10, 12]]
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![4, 6], vec![12, 14]]
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![3, 7], vec![11, 15]]
+        );
+    }
+
+    #[test]
+    fn test_t3_prod_axis() {
+        let a = vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]];
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![5, 12], vec![21, 32]]
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![3, 8], vec![35, 48]]
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![2, 12], vec![30, 56]]
+        );
+    }
+
+    #[test]
+    fn test_t3_min_axis() {
+        let a = vec![vec![vec![1, 8], vec![3, 4]], vec![vec![5, 6], vec![2, 7]]];
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![1, 6], vec![2, 4]]
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![1, 4], vec![2, 6]]
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![1, 3], vec![5, 2]]
+        );
+    }
+
+    #[test]
+    fn test_t3_max_axis() {
+        let a = vec![vec![vec![1, 8], vec![3, 4]], vec![vec![5, 6], vec![2, 7]]];
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![5, 8], vec![3, 7]]
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![3, 8], vec![5, 7]]
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![8, 4], vec![6, 7]]
+        );
+    }
+
+    #[test]
+    fn test_t3_mean_axis() {
+        let a = vec![
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+            vec![vec![5.0, 6.0], vec![7.0, 8.0]],
+        ];
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![3.0, 4.0], vec![5.0, 6.0]]
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![2.0, 3.0], vec![6.0, 7.0]]
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![1.5, 3.5], vec![5.5, 7.5]]
+        );
+    }
+
+    #[test]
+    fn test_t3_argmin_axis() {
+        let a = vec![vec![vec![1, 8], vec![3, 4]], vec![vec![5, 6], vec![2, 7]]];
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![0, 1], vec![1, 0]]
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![0, 1], vec![1, 0]]
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![0, 0], vec![0, 0]]
+        );
+    }
+
+    #[test]
+    fn test_t3_argmax_axis() {
+        let a = vec![vec![vec![1, 8], vec![3, 4]], vec![vec![5, 6], vec![2, 7]]];
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            vec![vec![1, 0], vec![0, 1]]
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            vec![vec![1, 0], vec![0, 1]]
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a, 2),
+            vec![vec![1, 1], vec![1, 1]]
         );
     }
 
     #[test]
     fn test_t1_t1_dot() {
-        assert_eq!(Backend::t1_t1_dot(vec![1, 2, 3], vec![4, 5, 6]), 32);
+        assert_eq!(Backend::t1_t1_dot::<3>(vec![1, 2, 3], vec![4, 5, 6]), 32);
+    }
+
+    #[test]
+    fn test_t2_t1_matvec() {
+        assert_eq!(
+            Backend::t2_t1_matvec::<2, 3>(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![2, 3, 4]),
+            vec![20, 47]
+        );
+    }
+
+    #[test]
+    fn test_t1_t2_vecmat() {
+        assert_eq!(
+            Backend::t1_t2_vecmat::<2, 3>(vec![2, 3], vec![vec![1, 2, 3], vec![4, 5, 6]]),
+            vec![14, 19, 24]
+        );
     }
 
     #[test]
     fn test_t2_t2_matmul() {
         assert_eq!(
-            Backend::t2_t2_matmul(
+            Backend::t2_t2_matmul::<2, 3, 2>(
                 vec![vec![1, 2, 3], vec![4, 5, 6]],
                 vec![vec![2, 3], vec![4, 5], vec![6, 7]]
             ),
             vec![vec![28, 34], vec![64, 79]]
         );
     }
+
+    #[test]
+    fn test_t2_t2_matmul_blocked() {
+        let a: Vec<i64> = (0..40 * 30).map(|i| i % 11 - 5).collect();
+        let b: Vec<i64> = (0..30 * 50).map(|i| i % 7 - 3).collect();
+        let result = Backend::t2_t2_matmul::<40, 30, 50>(
+            a.chunks(30).map(<[i64]>::to_vec).collect(),
+            b.chunks(50).map(<[i64]>::to_vec).collect(),
+        );
+        assert_eq!(result.concat(), gemm::naive(&a, &b, (40, 30, 50)));
+    }
+
+    #[test]
+    fn test_t3_t2_matmul() {
+        assert_eq!(
+            Backend::t3_t2_matmul::<2, 2, 2, 3>(
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]],
+                vec![vec![1, 0, 2], vec![0, 1, 3]]
+            ),
+            vec![
+                vec![vec![1, 2, 8], vec![3, 4, 18]],
+                vec![vec![5, 6, 28], vec![7, 8, 38]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_bmm() {
+        assert_eq!(
+            Backend::t3_t3_bmm::<2, 2, 2, 1>(
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]],
+                vec![vec![vec![1], vec![1]], vec![vec![2], vec![-1]]]
+            ),
+            vec![vec![vec![3], vec![7]], vec![vec![4], vec![6]]]
+        );
+    }
 }
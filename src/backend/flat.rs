@@ -0,0 +1,3334 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{
+    backend::{argmax, argmin, gemm, max, mean, min, prod, sum, Backend as BackendTrait},
+    num::{One, Zero},
+    tensor::{Matrix, Tensor3, Vector},
+};
+
+#[derive(Clone, Debug)]
+pub struct Backend {}
+
+/// A single row-major buffer viewed through per-axis strides.
+///
+/// Permuting only rewrites `shape` and `strides`; the buffer is gathered back into row-major
+/// order lazily, when a kernel needs contiguous memory.
+#[derive(Clone, Debug)]
+pub struct Strided<T, const N: usize> {
+    pub(crate) data: Vec<T>,
+    shape: [usize; N],
+    strides: [usize; N],
+}
+
+impl<T, const N: usize> Strided<T, N> {
+    pub fn new(data: Vec<T>, shape: [usize; N]) -> Self {
+        assert_eq!(data.len(), shape.iter().product::<usize>());
+        Self {
+            data,
+            shape,
+            strides: row_major_strides(shape),
+        }
+    }
+
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    pub fn strides(&self) -> [usize; N] {
+        self.strides
+    }
+
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == row_major_strides(self.shape)
+    }
+
+    pub fn permute(self, p: [usize; N]) -> Self {
+        let mut seen = [false; N];
+        for &axis in &p {
+            assert!(axis < N && !seen[axis], "improper permute");
+            seen[axis] = true;
+        }
+
+        Self {
+            data: self.data,
+            shape: p.map(|axis| self.shape[axis]),
+            strides: p.map(|axis| self.strides[axis]),
+        }
+    }
+
+    /// Returns the row-major buffer, or `None` if this is a permuted view of it.
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.is_contiguous().then_some(self.data.as_slice())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        indices(self.shape).map(|index| &self.data[self.offset(index)])
+    }
+
+    pub(crate) fn offset(&self, index: [usize; N]) -> usize {
+        index.iter().zip(self.strides).map(|(i, s)| i * s).sum()
+    }
+}
+
+impl<T: Clone, const N: usize> Strided<T, N> {
+    pub fn into_contiguous(self) -> Self {
+        if self.is_contiguous() {
+            return self;
+        }
+        let data = self.iter().cloned().collect();
+        Self::new(data, self.shape)
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_contiguous().data
+    }
+
+    /// Gathers the buffer into row-major order if needed and returns it.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if !self.is_contiguous() {
+            self.data = self.iter().cloned().collect();
+            self.strides = row_major_strides(self.shape);
+        }
+        &mut self.data
+    }
+}
+
+impl<T: Clone, const D0: usize> Vector<T, D0, Backend> {
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.repr.as_slice()
+    }
+
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.repr.make_contiguous()
+    }
+}
+
+impl<T: Clone, const D0: usize, const D1: usize> Matrix<T, D0, D1, Backend> {
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.repr.as_slice()
+    }
+
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.repr.make_contiguous()
+    }
+}
+
+impl<T: Clone, const D0: usize, const D1: usize, const D2: usize> Tensor3<T, D0, D1, D2, Backend> {
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.repr.as_slice()
+    }
+
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.repr.make_contiguous()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Strided<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone> BackendTrait<T> for Backend {
+    type Index = usize;
+    type Dimension = usize;
+    type T0Repr = T;
+    type T1Repr<const D0: usize> = Strided<T, 1>;
+    type T2Repr<const D0: usize, const D1: usize> = Strided<T, 2>;
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize> = Strided<T, 3>;
+
+    fn t0_from_value(v: T) -> Self::T0Repr {
+        v
+    }
+
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0> {
+        Strided::new(a.into(), [D0])
+    }
+
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1> {
+        Strided::new(a.into_iter().flatten().collect(), [D0, D1])
+    }
+
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Strided::new(a.into_iter().flatten().flatten().collect(), [D0, D1, D2])
+    }
+
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0> {
+        Strided::new(v, [D0])
+    }
+
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1> {
+        Strided::new(v, [D0, D1])
+    }
+
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Strided::new(v, [D0, D1, D2])
+    }
+
+    fn t0_into_value(a: Self::T0Repr) -> T {
+        a
+    }
+
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T> {
+        a.into_vec()
+    }
+
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T> {
+        a.into_vec()
+    }
+
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T> {
+        a.into_vec()
+    }
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t0_get(a: &Self::T0Repr) -> &T {
+        a
+    }
+
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T {
+        a
+    }
+
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        assert!(i < a.shape[0]);
+        &a.data[a.offset([i])]
+    }
+
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        assert!(i < a.shape[0]);
+        let offset = a.offset([i]);
+        &mut a.data[offset]
+    }
+
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        assert!(i < a.shape[0] && j < a.shape[1]);
+        &a.data[a.offset([i, j])]
+    }
+
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        assert!(i < a.shape[0] && j < a.shape[1]);
+        let offset = a.offset([i, j]);
+        &mut a.data[offset]
+    }
+
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        assert!(i < a.shape[0] && j < a.shape[1] && k < a.shape[2]);
+        &a.data[a.offset([i, j, k])]
+    }
+
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        assert!(i < a.shape[0] && j < a.shape[1] && k < a.shape[2]);
+        let offset = a.offset([i, j, k]);
+        &mut a.data[offset]
+    }
+
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        a.data.get_unchecked(a.offset([i]))
+    }
+
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        let offset = a.offset([i]);
+        a.data.get_unchecked_mut(offset)
+    }
+
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        a.data.get_unchecked(a.offset([i, j]))
+    }
+
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        let offset = a.offset([i, j]);
+        a.data.get_unchecked_mut(offset)
+    }
+
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        a.data.get_unchecked(a.offset([i, j, k]))
+    }
+
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        let offset = a.offset([i, j, k]);
+        a.data.get_unchecked_mut(offset)
+    }
+
+    fn t0_zero() -> Self::T0Repr
+    where
+        T: Zero,
+    {
+        T::zero()
+    }
+
+    fn t0_one() -> Self::T0Repr
+    where
+        T: One,
+    {
+        T::one()
+    }
+
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: Zero + Copy,
+    {
+        Strided::new(vec![T::zero(); D0], [D0])
+    }
+
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: One + Copy,
+    {
+        Strided::new(vec![T::one(); D0], [D0])
+    }
+
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: Zero + Copy,
+    {
+        Strided::new(vec![T::zero(); D0 * D1], [D0, D1])
+    }
+
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: One + Copy,
+    {
+        Strided::new(vec![T::one(); D0 * D1], [D0, D1])
+    }
+
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: One + Copy,
+    {
+        Strided::new(vec![T::one(); D0 * D1 * D2], [D0, D1, D2])
+    }
+
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Zero + Copy,
+    {
+        Strided::new(vec![T::zero(); D0 * D1 * D2], [D0, D1, D2])
+    }
+
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
+    where
+        T: Zero + One + Copy,
+    {
+        let mut matrix = Self::t2_zeros::<D0, D0>();
+        for i in 0..D0 {
+            matrix.data[i * D0 + i] = T::one();
+        }
+        matrix
+    }
+
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
+    where
+        T: Zero + Copy,
+    {
+        a.permute([1, 0])
+    }
+
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (usize, usize),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy,
+    {
+        a.permute([p.0, p.1])
+    }
+
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        p: (usize, usize, usize),
+    ) -> Self::T3Repr<RD0, RD1, RD2>
+    where
+        T: Zero + Copy,
+    {
+        a.permute([p.0, p.1, p.2])
+    }
+
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0> {
+        concat(a, b, 0)
+    }
+
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1> {
+        concat(a, b, axis)
+    }
+
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: usize,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        concat(a, b, axis)
+    }
+
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1> {
+        stack(a.into())
+    }
+
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        stack(a.into())
+    }
+
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        at: usize,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>) {
+        split(a, at, 0)
+    }
+
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>) {
+        split(a, at, axis)
+    }
+
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>) {
+        split(a, at, axis)
+    }
+
+    fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Add<Output = T>,
+    {
+        a + b
+    }
+
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        map(a, |a| a + b)
+    }
+
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T>,
+    {
+        zip(a, b, |a, b| a + b)
+    }
+
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        map(a, |a| a + b)
+    }
+
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a + b)
+    }
+
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T>,
+    {
+        zip(a, b, |a, b| a + b)
+    }
+
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        map(a, |a| a + b)
+    }
+
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a + b)
+    }
+
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        broadcast(a, &b, [1, 2], |a, b| a + b)
+    }
+
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T>,
+    {
+        zip(a, b, |a, b| a + b)
+    }
+
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>,
+    {
+        a - b
+    }
+
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        map(a, |a| a - b)
+    }
+
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>,
+    {
+        zip(a, b, |a, b| a - b)
+    }
+
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        map(a, |a| a - b)
+    }
+
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a - b)
+    }
+
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>,
+    {
+        zip(a, b, |a, b| a - b)
+    }
+
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        map(a, |a| a - b)
+    }
+
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a - b)
+    }
+
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        broadcast(a, &b, [1, 2], |a, b| a - b)
+    }
+
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>,
+    {
+        zip(a, b, |a, b| a - b)
+    }
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>,
+    {
+        a * b
+    }
+
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        map(a, |a| a * b)
+    }
+
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>,
+    {
+        zip(a, b, |a, b| a * b)
+    }
+
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        map(a, |a| a * b)
+    }
+
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a * b)
+    }
+
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>,
+    {
+        zip(a, b, |a, b| a * b)
+    }
+
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        map(a, |a| a * b)
+    }
+
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a * b)
+    }
+
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        broadcast(a, &b, [1, 2], |a, b| a * b)
+    }
+
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>,
+    {
+        zip(a, b, |a, b| a * b)
+    }
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>,
+    {
+        a / b
+    }
+
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        map(a, |a| a / b)
+    }
+
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>,
+    {
+        zip(a, b, |a, b| a / b)
+    }
+
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        map(a, |a| a / b)
+    }
+
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a / b)
+    }
+
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>,
+    {
+        zip(a, b, |a, b| a / b)
+    }
+
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        map(a, |a| a / b)
+    }
+
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a / b)
+    }
+
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        broadcast(a, &b, [1, 2], |a, b| a / b)
+    }
+
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>,
+    {
+        zip(a, b, |a, b| a / b)
+    }
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>,
+    {
+        a % b
+    }
+
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        map(a, |a| a % b)
+    }
+
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>,
+    {
+        zip(a, b, |a, b| a % b)
+    }
+
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        map(a, |a| a % b)
+    }
+
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a % b)
+    }
+
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>,
+    {
+        zip(a, b, |a, b| a % b)
+    }
+
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        map(a, |a| a % b)
+    }
+
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        broadcast(a, &b, [along], |a, b| a % b)
+    }
+
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        broadcast(a, &b, [1, 2], |a, b| a % b)
+    }
+
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>,
+    {
+        zip(a, b, |a, b| a % b)
+    }
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Neg<Output = T>,
+    {
+        -a
+    }
+
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>,
+    {
+        map(a, |a| -a)
+    }
+
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>,
+    {
+        map(a, |a| -a)
+    }
+
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>,
+    {
+        map(a, |a| -a)
+    }
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr {
+        f(a)
+    }
+
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0> {
+        map(a, f)
+    }
+
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        map(a, f)
+    }
+
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        map(a, f)
+    }
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr {
+        f(a, b)
+    }
+
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0> {
+        zip(a, b, f)
+    }
+
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        zip(a, b, f)
+    }
+
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        zip(a, b, f)
+    }
+
+    fn t0_fold<U>(a: Self::T0Repr, init: U, mut f: impl FnMut(U, T) -> U) -> U {
+        f(init, a)
+    }
+
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U {
+        a.into_contiguous().data.into_iter().fold(init, f)
+    }
+
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_contiguous().data.into_iter().fold(init, f)
+    }
+
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        a.into_contiguous().data.into_iter().fold(init, f)
+    }
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        sum(&a.into_vec())
+    }
+
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        prod(&a.into_vec())
+    }
+
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        min(&a.into_vec())
+    }
+
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        max(&a.into_vec())
+    }
+
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        mean(&a.into_vec())
+    }
+
+    fn t1_argmin<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmin(&a.into_vec())
+    }
+
+    fn t1_argmax<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        argmax(&a.into_vec())
+    }
+
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        reduce(a, axis, sum)
+    }
+
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        reduce(a, axis, prod)
+    }
+
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, min)
+    }
+
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, max)
+    }
+
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        reduce(a, axis, mean)
+    }
+
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Strided<usize, 1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, argmin)
+    }
+
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Strided<usize, 1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, argmax)
+    }
+
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        reduce(a, axis, sum)
+    }
+
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        reduce(a, axis, prod)
+    }
+
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, min)
+    }
+
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, max)
+    }
+
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        reduce(a, axis, mean)
+    }
+
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Strided<usize, 2>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, argmin)
+    }
+
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Strided<usize, 2>
+    where
+        T: PartialOrd + Copy,
+    {
+        reduce(a, axis, argmax)
+    }
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        assert_eq!(a.shape, b.shape);
+        a.into_vec()
+            .into_iter()
+            .zip(b.into_vec())
+            .fold(T::zero(), |acc, (a, b)| acc + a * b)
+    }
+
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.shape[1], b.shape[0]);
+        let [m, k] = a.shape;
+        Strided::new(gemm::matmul(&a.into_vec(), &b.into_vec(), (m, k, 1)), [m])
+    }
+
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.shape[0], b.shape[0]);
+        let [k, n] = b.shape;
+        Strided::new(gemm::matmul(&a.into_vec(), &b.into_vec(), (1, k, n)), [n])
+    }
+
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.shape[1], b.shape[0]);
+        let ([m, k], [_, n]) = (a.shape, b.shape);
+        let (a, b) = (a.into_vec(), b.into_vec());
+        Strided::new(gemm::matmul(&a, &b, (m, k, n)), [m, n])
+    }
+
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.shape[2], b.shape[0]);
+        let ([batch, m, k], [_, n]) = (a.shape, b.shape);
+        let (a, b) = (a.into_vec(), b.into_vec());
+        let data = (0..batch)
+            .flat_map(|i| gemm::matmul(&a[i * m * k..(i + 1) * m * k], &b, (m, k, n)))
+            .collect();
+        Strided::new(data, [batch, m, n])
+    }
+
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(a.shape[0], b.shape[0]);
+        assert_eq!(a.shape[2], b.shape[1]);
+        let ([batch, m, k], [_, _, n]) = (a.shape, b.shape);
+        let (a, b) = (a.into_vec(), b.into_vec());
+        let data = (0..batch)
+            .flat_map(|i| {
+                gemm::matmul(
+                    &a[i * m * k..(i + 1) * m * k],
+                    &b[i * k * n..(i + 1) * k * n],
+                    (m, k, n),
+                )
+            })
+            .collect();
+        Strided::new(data, [batch, m, n])
+    }
+}
+
+fn row_major_strides<const N: usize>(shape: [usize; N]) -> [usize; N] {
+    let mut strides = [1; N];
+    for i in (0..N.saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+/// Iterates over every index of `shape` in row-major order.
+fn indices<const N: usize>(shape: [usize; N]) -> impl Iterator<Item = [usize; N]> {
+    let first = (!shape.contains(&0)).then_some([0; N]);
+    std::iter::successors(first, move |&index| {
+        let mut index = index;
+        for axis in (0..N).rev() {
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                return Some(index);
+            }
+            index[axis] = 0;
+        }
+        None
+    })
+}
+
+fn map<T, U, const N: usize>(a: Strided<T, N>, f: impl FnMut(T) -> U) -> Strided<U, N> {
+    Strided {
+        data: a.data.into_iter().map(f).collect(),
+        shape: a.shape,
+        strides: a.strides,
+    }
+}
+
+fn zip<T: Clone, const N: usize>(
+    a: Strided<T, N>,
+    b: Strided<T, N>,
+    mut f: impl FnMut(T, T) -> T,
+) -> Strided<T, N> {
+    assert_eq!(a.shape, b.shape);
+    let (a, b) = if a.strides == b.strides {
+        (a, b)
+    } else {
+        (a.into_contiguous(), b.into_contiguous())
+    };
+    Strided {
+        data: a
+            .data
+            .into_iter()
+            .zip(b.data)
+            .map(|(a, b)| f(a, b))
+            .collect(),
+        shape: a.shape,
+        strides: a.strides,
+    }
+}
+
+/// Combines every element of `a` with the element of `b` whose index is `a`'s index projected
+/// onto `axes`.
+fn broadcast<T: Copy, const N: usize, const M: usize>(
+    a: Strided<T, N>,
+    b: &Strided<T, M>,
+    axes: [usize; M],
+    mut f: impl FnMut(T, T) -> T,
+) -> Strided<T, N> {
+    for (j, &axis) in axes.iter().enumerate() {
+        assert_eq!(a.shape[axis], b.shape[j]);
+    }
+    let a = a.into_contiguous();
+    let data = indices(a.shape)
+        .zip(a.data)
+        .map(|(index, a)| f(a, b.data[b.offset(axes.map(|axis| index[axis]))]))
+        .collect();
+    Strided::new(data, a.shape)
+}
+
+/// Applies `f` to every lane along `axis`, keeping the remaining axes in order.
+fn reduce<T: Clone, U, const N: usize, const M: usize>(
+    a: Strided<T, N>,
+    axis: usize,
+    f: impl FnMut(&[T]) -> U,
+) -> Strided<U, M> {
+    assert!(axis < N && M + 1 == N, "improper axis");
+    let mut p = [axis; N];
+    let mut shape = [0; M];
+    for (i, other) in (0..N).filter(|&other| other != axis).enumerate() {
+        p[i] = other;
+        shape[i] = a.shape[other];
+    }
+    let n = a.shape[axis];
+    let a = a.permute(p).into_contiguous();
+    let data = (0..shape.iter().product())
+        .map(|i| &a.data[i * n..(i + 1) * n])
+        .map(f)
+        .collect();
+    Strided::new(data, shape)
+}
+
+fn concat<T: Clone, const N: usize>(
+    a: Strided<T, N>,
+    b: Strided<T, N>,
+    axis: usize,
+) -> Strided<T, N> {
+    for i in (0..N).filter(|&i| i != axis) {
+        assert_eq!(a.shape[i], b.shape[i]);
+    }
+    let (a, b) = (a.into_contiguous(), b.into_contiguous());
+    let outer = a.shape[..axis].iter().product();
+    let a_chunk = a.shape[axis..].iter().product();
+    let b_chunk = b.shape[axis..].iter().product();
+    let mut shape = a.shape;
+    shape[axis] += b.shape[axis];
+
+    let mut data = Vec::with_capacity(a.data.len() + b.data.len());
+    let (mut a, mut b) = (a.data.into_iter(), b.data.into_iter());
+    for _ in 0..outer {
+        data.extend(a.by_ref().take(a_chunk));
+        data.extend(b.by_ref().take(b_chunk));
+    }
+    Strided::new(data, shape)
+}
+
+fn split<T: Clone, const N: usize>(
+    a: Strided<T, N>,
+    at: usize,
+    axis: usize,
+) -> (Strided<T, N>, Strided<T, N>) {
+    assert!(at <= a.shape[axis]);
+    let a = a.into_contiguous();
+    let outer = a.shape[..axis].iter().product();
+    let inner: usize = a.shape[axis + 1..].iter().product();
+    let (mut left_shape, mut right_shape) = (a.shape, a.shape);
+    left_shape[axis] = at;
+    right_shape[axis] = a.shape[axis] - at;
+
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    let mut a = a.data.into_iter();
+    for _ in 0..outer {
+        left.extend(a.by_ref().take(at * inner));
+        right.extend(a.by_ref().take((right_shape[axis]) * inner));
+    }
+    (
+        Strided::new(left, left_shape),
+        Strided::new(right, right_shape),
+    )
+}
+
+fn stack<T: Clone, const N: usize, const M: usize>(a: Vec<Strided<T, M>>) -> Strided<T, N> {
+    assert!(M + 1 == N);
+    let mut shape = [a.len(); N];
+    if let Some(first) = a.first() {
+        shape[1..].copy_from_slice(&first.shape);
+    }
+    let mut data = Vec::with_capacity(shape.iter().product());
+    for a in a {
+        assert_eq!(a.shape, shape[1..]);
+        data.extend(a.into_vec());
+    }
+    Strided::new(data, shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s1<T>(a: Vec<T>) -> Strided<T, 1> {
+        let shape = [a.len()];
+        Strided::new(a, shape)
+    }
+
+    fn s2<T>(a: Vec<Vec<T>>) -> Strided<T, 2> {
+        let shape = [a.len(), a[0].len()];
+        Strided::new(a.into_iter().flatten().collect(), shape)
+    }
+
+    fn s3<T>(a: Vec<Vec<Vec<T>>>) -> Strided<T, 3> {
+        let shape = [a.len(), a[0].len(), a[0][0].len()];
+        Strided::new(a.into_iter().flatten().flatten().collect(), shape)
+    }
+
+    #[test]
+    fn test_t0_from_value() {
+        let value: u8 = Backend::t0_from_value(7);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_t1_from_array() {
+        let a: Strided<u8, 1> = Backend::t1_from_array([1, 2, 3]);
+        assert_eq!(a, s1(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_t2_from_array() {
+        let a: Strided<u8, 2> = Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a, s2(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_t3_from_array() {
+        let a: Strided<u8, 3> = Backend::t3_from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert_eq!(
+            a,
+            s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t2_from_vec() {
+        let a: Strided<u8, 2> = Backend::t2_from_vec::<3, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, s2(vec![vec![1, 2], vec![3, 4], vec![5, 6]]));
+    }
+
+    #[test]
+    fn test_t3_from_vec() {
+        let a: Strided<u8, 3> = Backend::t3_from_vec::<3, 1, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            a,
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]])
+        );
+    }
+
+    #[test]
+    fn test_t2_into_vec() {
+        assert_eq!(
+            Backend::t2_into_vec::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_t3_into_vec() {
+        assert_eq!(
+            Backend::t3_into_vec::<2, 2, 2>(s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]]
+            ])),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_t0_get() {
+        let mut a: u8 = 3;
+        assert_eq!(*Backend::t0_get(&a), 3);
+        *Backend::t0_get_mut(&mut a) = 4;
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn test_t1_get() {
+        let mut a = s1(vec![1, 2, 3]);
+        assert_eq!(*Backend::t1_get::<3>(&a, 1), 2);
+        *Backend::t1_get_mut::<3>(&mut a, 1) = 5;
+        assert_eq!(a, s1(vec![1, 5, 3]));
+    }
+
+    #[test]
+    fn test_t2_get() {
+        let mut a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(*Backend::t2_get::<2, 3>(&a, 1, 2), 6);
+        *Backend::t2_get_mut::<2, 3>(&mut a, 1, 2) = 7;
+        assert_eq!(a, s2(vec![vec![1, 2, 3], vec![4, 5, 7]]));
+    }
+
+    #[test]
+    fn test_t3_get() {
+        let mut a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(*Backend::t3_get::<2, 2, 2>(&a, 1, 0, 1), 6);
+        *Backend::t3_get_mut::<2, 2, 2>(&mut a, 1, 0, 1) = 9;
+        assert_eq!(
+            a,
+            s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 9], vec![7, 8]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        let b = s2(vec![vec![5, 6], vec![7, 8]]);
+        let c = s1(vec![5, 6]);
+        unsafe {
+            assert_eq!(*Backend::t3_get_unchecked::<2, 2, 2>(&a, 0, 1, 0), 3);
+            *Backend::t3_get_unchecked_mut::<2, 2, 2>(&mut a, 0, 1, 0) = 0;
+            assert_eq!(*Backend::t2_get_unchecked::<2, 2>(&b, 1, 1), 8);
+            assert_eq!(*Backend::t1_get_unchecked::<2>(&c, 0), 5);
+        }
+        assert_eq!(*Backend::t3_get::<2, 2, 2>(&a, 0, 1, 0), 0);
+    }
+
+    #[test]
+    fn test_t0_zero() {
+        let zero: u8 = Backend::t0_zero();
+        assert_eq!(zero, 0);
+    }
+
+    #[test]
+    fn test_t0_one() {
+        let one: u8 = Backend::t0_one();
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn test_t1_zeros() {
+        let zeros: Strided<u8, 1> = Backend::t1_zeros::<3>();
+        assert_eq!(zeros, s1(vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn test_t1_ones() {
+        let ones: Strided<u8, 1> = Backend::t1_ones::<3>();
+        assert_eq!(ones, s1(vec![1, 1, 1]));
+    }
+
+    #[test]
+    fn test_t2_zeros() {
+        let zeros: Strided<u8, 2> = Backend::t2_zeros::<2, 3>();
+        assert_eq!(zeros, s2(vec![vec![0, 0, 0], vec![0, 0, 0]]));
+    }
+
+    #[test]
+    fn test_t2_ones() {
+        let ones: Strided<u8, 2> = Backend::t2_ones::<2, 3>();
+        assert_eq!(ones, s2(vec![vec![1, 1, 1], vec![1, 1, 1]]));
+    }
+
+    #[test]
+    fn test_t3_zeros() {
+        let zeros: Strided<u8, 3> = Backend::t3_zeros::<2, 3, 4>();
+        assert_eq!(
+            zeros,
+            s3(vec![
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]],
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_ones() {
+        let ones: Strided<u8, 3> = Backend::t3_ones::<2, 3, 4>();
+        assert_eq!(
+            ones,
+            s3(vec![
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]],
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t2_identity() {
+        let eye: Strided<u8, 2> = Backend::t2_identity::<3>();
+        assert_eq!(eye, s2(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]));
+    }
+
+    #[test]
+    fn test_t2_transpose() {
+        assert_eq!(
+            Backend::t2_transpose::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            s2(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t2_permute() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(Backend::t2_permute::<2, 3, 2, 3>(a.clone(), (0, 1)), a);
+        assert_eq!(
+            Backend::t2_permute::<2, 3, 3, 2>(a, (1, 0)),
+            s2(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_permute() {
+        let input = s3(vec![
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+            vec![vec![10, 11, 12], vec![13, 14, 15], vec![16, 17, 18]],
+        ]);
+        let permuted = Backend::t3_permute::<2, 3, 3, 3, 2, 3>(input, (2, 0, 1));
+        let expected = s3(vec![
+            vec![vec![1, 4, 7], vec![10, 13, 16]],
+            vec![vec![2, 5, 8], vec![11, 14, 17]],
+            vec![vec![3, 6, 9], vec![12, 15, 18]],
+        ]);
+        assert_eq!(permuted, expected);
+    }
+
+    #[test]
+    fn test_t1_concat() {
+        assert_eq!(
+            Backend::t1_concat::<2, 3, 5>(s1(vec![1, 2]), s1(vec![3, 4, 5])),
+            s1(vec![1, 2, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_t2_concat() {
+        let a = s2(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 1, 2, 3, 2>(a.clone(), s2(vec![vec![5, 6]]), 0),
+            s2(vec![vec![1, 2], vec![3, 4], vec![5, 6]])
+        );
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 2, 1, 2, 3>(a, s2(vec![vec![5], vec![6]]), 1),
+            s2(vec![vec![1, 2, 5], vec![3, 4, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_concat() {
+        let a = s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]]);
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 1, 1, 2, 3, 1, 2>(
+                a.clone(),
+                s3(vec![vec![vec![5, 6]]]),
+                0
+            ),
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]])
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 2, 2, 2, 2>(
+                a.clone(),
+                s3(vec![vec![vec![5, 6]], vec![vec![7, 8]]]),
+                1
+            ),
+            s3(vec![
+                vec![vec![1, 2], vec![5, 6]],
+                vec![vec![3, 4], vec![7, 8]]
+            ])
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 1, 2, 1, 3>(
+                a,
+                s3(vec![vec![vec![5]], vec![vec![6]]]),
+                2
+            ),
+            s3(vec![vec![vec![1, 2, 5]], vec![vec![3, 4, 6]]])
+        );
+    }
+
+    #[test]
+    fn test_t2_stack() {
+        assert_eq!(
+            Backend::t2_stack::<2, 2>([s1(vec![1, 2]), s1(vec![3, 4])]),
+            s2(vec![vec![1, 2], vec![3, 4]])
+        );
+    }
+
+    #[test]
+    fn test_t3_stack() {
+        assert_eq!(
+            Backend::t3_stack::<2, 1, 2>([s2(vec![vec![1, 2]]), s2(vec![vec![3, 4]])]),
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]])
+        );
+    }
+
+    #[test]
+    fn test_t1_split() {
+        assert_eq!(
+            Backend::t1_split::<5, 2, 3>(s1(vec![1, 2, 3, 4, 5]), 2),
+            (s1(vec![1, 2]), s1(vec![3, 4, 5]))
+        );
+    }
+
+    #[test]
+    fn test_t2_split() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_split::<2, 3, 1, 3, 1, 3>(a.clone(), 1, 0),
+            (s2(vec![vec![1, 2, 3]]), s2(vec![vec![4, 5, 6]]))
+        );
+        assert_eq!(
+            Backend::t2_split::<2, 3, 2, 1, 2, 2>(a, 1, 1),
+            (s2(vec![vec![1], vec![4]]), s2(vec![vec![2, 3], vec![5, 6]]))
+        );
+    }
+
+    #[test]
+    fn test_t3_split() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 1, 2, 2, 1, 2, 2>(a.clone(), 1, 0),
+            (
+                s3(vec![vec![vec![1, 2], vec![3, 4]]]),
+                s3(vec![vec![vec![5, 6], vec![7, 8]]])
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 1, 2, 2, 1, 2>(a.clone(), 1, 1),
+            (
+                s3(vec![vec![vec![1, 2]], vec![vec![5, 6]]]),
+                s3(vec![vec![vec![3, 4]], vec![vec![7, 8]]])
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 2, 1, 2, 2, 1>(a, 1, 2),
+            (
+                s3(vec![vec![vec![1], vec![3]], vec![vec![5], vec![7]]]),
+                s3(vec![vec![vec![2], vec![4]], vec![vec![6], vec![8]]])
+            )
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_add() {
+        assert_eq!(Backend::t0_t0_add(1, 2), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_add() {
+        assert_eq!(
+            Backend::t1_t0_add::<3>(s1(vec![1, 2, 3]), 2),
+            s1(vec![3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_add() {
+        assert_eq!(
+            Backend::t1_t1_add::<3>(s1(vec![1, 2, 3]), s1(vec![4, 5, 6])),
+            s1(vec![5, 7, 9])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_add() {
+        assert_eq!(
+            Backend::t2_t0_add::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]]), 2),
+            s2(vec![vec![3, 4, 5], vec![6, 7, 8]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_0() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 2>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s2(vec![vec![3, 4, 5], vec![7, 8, 9]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_1() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![3, 5, 7], vec![6, 8, 10]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_add() {
+        assert_eq!(
+            Backend::t2_t2_add::<2, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s2(vec![vec![2, 3, 4], vec![5, 6, 7]])
+            ),
+            s2(vec![vec![3, 5, 7], vec![9, 11, 13]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_sub() {
+        assert_eq!(Backend::t0_t0_sub(9, 3), 6);
+    }
+
+    #[test]
+    fn test_t1_t0_sub() {
+        assert_eq!(
+            Backend::t1_t0_sub::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![4, 6, 7])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_sub() {
+        assert_eq!(
+            Backend::t1_t1_sub::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![2, 5, 7])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_sub() {
+        assert_eq!(
+            Backend::t2_t0_sub::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![4, 6, 7], vec![8, 10, 13]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![4, 6, 7], vec![6, 8, 11]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![4, 5, 5], vec![8, 9, 11]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_sub() {
+        assert_eq!(
+            Backend::t2_t2_sub::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![2, 5, 7], vec![7, 7, 8]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_mul() {
+        assert_eq!(Backend::t0_t0_mul(9, 3), 27);
+    }
+
+    #[test]
+    fn test_t1_t0_mul() {
+        assert_eq!(
+            Backend::t1_t0_mul::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![12, 16, 18])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_mul() {
+        assert_eq!(
+            Backend::t1_t1_mul::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![24, 24, 18])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_mul() {
+        assert_eq!(
+            Backend::t2_t0_mul::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![12, 16, 18], vec![20, 24, 30]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![12, 16, 18], vec![40, 48, 60]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![12, 24, 36], vec![20, 36, 60]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_mul() {
+        assert_eq!(
+            Backend::t2_t2_mul::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![24, 24, 18], vec![30, 60, 105]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_div() {
+        assert_eq!(Backend::t0_t0_div(9, 3), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_div() {
+        assert_eq!(
+            Backend::t1_t0_div::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![3, 4, 4])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_div() {
+        assert_eq!(
+            Backend::t1_t1_div::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![1, 2, 4])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_div() {
+        assert_eq!(
+            Backend::t2_t0_div::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![3, 4, 4], vec![5, 6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_0() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![3, 4, 4], vec![2, 3, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_1() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![3, 2, 2], vec![5, 4, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_div() {
+        assert_eq!(
+            Backend::t2_t2_div::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![1, 2, 4], vec![3, 2, 2]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_rem() {
+        assert_eq!(Backend::t0_t0_rem(9, 3), 0);
+    }
+
+    #[test]
+    fn test_t1_t0_rem() {
+        assert_eq!(
+            Backend::t1_t0_rem::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_rem() {
+        assert_eq!(
+            Backend::t1_t1_rem::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![2, 2, 1])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_rem() {
+        assert_eq!(
+            Backend::t2_t0_rem::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![0, 0, 1], vec![0, 0, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![0, 0, 1], vec![2, 0, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![0, 2, 1], vec![0, 0, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_rem() {
+        assert_eq!(
+            Backend::t2_t2_rem::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![2, 2, 1], vec![1, 2, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_add() {
+        assert_eq!(
+            Backend::t3_t0_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![14, 16], vec![17, 20]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_0() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![15, 17], vec![18, 21]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_1() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![9, 11], vec![13, 14]],
+                vec![vec![15, 17], vec![19, 22]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_2() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![10, 13], vec![13, 15]],
+                vec![vec![16, 19], vec![19, 23]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_add() {
+        assert_eq!(
+            Backend::t3_t2_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![8, 11], vec![13, 15]],
+                vec![vec![14, 17], vec![19, 23]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_add() {
+        assert_eq!(
+            Backend::t3_t3_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![7, 10], vec![12, 14]],
+                vec![vec![17, 20], vec![22, 26]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_sub() {
+        assert_eq!(
+            Backend::t3_t0_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![10, 12], vec![13, 16]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![9, 11], vec![12, 15]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![3, 5], vec![5, 6]],
+                vec![vec![9, 11], vec![11, 14]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_2() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![2, 3], vec![5, 5]],
+                vec![vec![8, 9], vec![11, 13]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_sub() {
+        assert_eq!(
+            Backend::t3_t2_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![4, 5], vec![5, 5]],
+                vec![vec![10, 11], vec![11, 13]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_sub() {
+        assert_eq!(
+            Backend::t3_t3_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![5, 6], vec![6, 6]],
+                vec![vec![7, 8], vec![8, 10]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_mul() {
+        assert_eq!(
+            Backend::t3_t0_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![24, 28], vec![30, 36]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![36, 42], vec![45, 54]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![18, 24], vec![36, 40]],
+                vec![vec![36, 42], vec![60, 72]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_2() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![24, 40], vec![36, 50]],
+                vec![vec![48, 70], vec![60, 90]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_mul() {
+        assert_eq!(
+            Backend::t3_t2_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![12, 24], vec![36, 50]],
+                vec![vec![24, 42], vec![60, 90]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_mul() {
+        assert_eq!(
+            Backend::t3_t3_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![6, 16], vec![27, 40]],
+                vec![vec![60, 84], vec![105, 144]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_div() {
+        assert_eq!(
+            Backend::t3_t0_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![3, 4], vec![4, 5]],
+                vec![vec![6, 7], vec![7, 9]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_0() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![3, 4], vec![4, 5]],
+                vec![vec![4, 4], vec![5, 6]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_1() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![2, 2], vec![2, 2]],
+                vec![vec![4, 4], vec![3, 4]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_2() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![1, 1], vec![2, 2]],
+                vec![vec![3, 2], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_div() {
+        assert_eq!(
+            Backend::t3_t2_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![3, 2], vec![2, 2]],
+                vec![vec![6, 4], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_div() {
+        assert_eq!(
+            Backend::t3_t3_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![6, 4], vec![3, 2]],
+                vec![vec![2, 2], vec![2, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_rem() {
+        assert_eq!(
+            Backend::t3_t0_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![1, 0]],
+                vec![vec![0, 0], vec![1, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![1, 0]],
+                vec![vec![0, 2], vec![0, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![0, 2], vec![1, 2]],
+                vec![vec![0, 2], vec![3, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_2() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![2, 3], vec![1, 0]],
+                vec![vec![0, 4], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_rem() {
+        assert_eq!(
+            Backend::t3_t2_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![0, 2], vec![1, 0]],
+                vec![vec![0, 2], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_rem() {
+        assert_eq!(
+            Backend::t3_t3_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![0, 2]],
+                vec![vec![2, 2], vec![1, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t0_neg() {
+        assert_eq!(Backend::t0_neg(3), -3);
+    }
+
+    #[test]
+    fn test_t1_neg() {
+        assert_eq!(
+            Backend::t1_neg::<3>(s1(vec![1, -2, 3])),
+            s1(vec![-1, 2, -3])
+        );
+    }
+
+    #[test]
+    fn test_t2_neg() {
+        assert_eq!(
+            Backend::t2_neg::<2, 2>(s2(vec![vec![1, -2], vec![3, 0]])),
+            s2(vec![vec![-1, 2], vec![-3, 0]])
+        );
+    }
+
+    #[test]
+    fn test_t3_neg() {
+        assert_eq!(
+            Backend::t3_neg::<2, 1, 2>(s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]])),
+            s3(vec![vec![vec![-1, 2]], vec![vec![-3, 0]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_map() {
+        assert_eq!(Backend::t0_map(3, |a| a * 2), 6);
+    }
+
+    #[test]
+    fn test_t1_map() {
+        assert_eq!(
+            Backend::t1_map::<3>(s1(vec![1, -2, 3]), |a| a * a),
+            s1(vec![1, 4, 9])
+        );
+    }
+
+    #[test]
+    fn test_t2_map() {
+        assert_eq!(
+            Backend::t2_map::<2, 2>(s2(vec![vec![1, -2], vec![3, 0]]), |a| a + 1),
+            s2(vec![vec![2, -1], vec![4, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t3_map() {
+        assert_eq!(
+            Backend::t3_map::<2, 1, 2>(s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]]), |a| a - 1),
+            s3(vec![vec![vec![0, -3]], vec![vec![2, -1]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_zip_with() {
+        assert_eq!(Backend::t0_zip_with(3, 4, |a, b| a * b), 12);
+    }
+
+    #[test]
+    fn test_t1_zip_with() {
+        assert_eq!(
+            Backend::t1_zip_with::<3>(s1(vec![1, -2, 3]), s1(vec![0, 5, 2]), |a, b| a.max(b)),
+            s1(vec![1, 5, 3])
+        );
+    }
+
+    #[test]
+    fn test_t2_zip_with() {
+        assert_eq!(
+            Backend::t2_zip_with::<2, 2>(
+                s2(vec![vec![1, 2], vec![3, 4]]),
+                s2(vec![vec![5, 6], vec![7, 8]]),
+                |a, b| a * 10 + b
+            ),
+            s2(vec![vec![15, 26], vec![37, 48]])
+        );
+    }
+
+    #[test]
+    fn test_t3_zip_with() {
+        assert_eq!(
+            Backend::t3_zip_with::<2, 1, 2>(
+                s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]]),
+                s3(vec![vec![vec![1, 1]], vec![vec![1, 1]]]),
+                |a, b| a - b
+            ),
+            s3(vec![vec![vec![0, -3]], vec![vec![2, -1]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_fold() {
+        assert_eq!(Backend::t0_fold(3, 1, |acc, a| acc + a), 4);
+    }
+
+    #[test]
+    fn test_t1_fold() {
+        assert_eq!(
+            Backend::t1_fold::<3, _>(s1(vec![1, 2, 3]), 0, |acc, a| acc * 10 + a),
+            123
+        );
+    }
+
+    #[test]
+    fn test_t2_fold() {
+        assert_eq!(
+            Backend::t2_fold::<2, 2, _>(s2(vec![vec![1, 2], vec![3, 4]]), 0, |acc, a| acc * 10 + a),
+            1234
+        );
+    }
+
+    #[test]
+    fn test_fold_follows_logical_order() {
+        let a = Backend::t2_transpose::<2, 2>(s2(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(
+            Backend::t2_fold::<2, 2, _>(a, 0, |acc, a| acc * 10 + a),
+            1324
+        );
+    }
+
+    #[test]
+    fn test_t3_fold() {
+        assert_eq!(
+            Backend::t3_fold::<2, 1, 2, _>(
+                s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]]),
+                Vec::new(),
+                |mut acc, a| {
+                    acc.push(a);
+                    acc
+                }
+            ),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_t1_sum() {
+        assert_eq!(Backend::t1_sum::<3>(s1(vec![1, 2, 3])), 6);
+    }
+
+    #[test]
+    fn test_t1_prod() {
+        assert_eq!(Backend::t1_prod::<3>(s1(vec![2, 3, 4])), 24);
+    }
+
+    #[test]
+    fn test_t1_min() {
+        assert_eq!(Backend::t1_min::<3>(s1(vec![3, 1, 2])), 1);
+    }
+
+    #[test]
+    fn test_t1_max() {
+        assert_eq!(Backend::t1_max::<3>(s1(vec![3, 1, 2])), 3);
+    }
+
+    #[test]
+    fn test_t1_mean() {
+        assert_eq!(Backend::t1_mean::<3>(s1(vec![1.0, 2.0, 6.0])), 3.0);
+    }
+
+    #[test]
+    fn test_t1_argmin() {
+        assert_eq!(Backend::t1_argmin::<4>(s1(vec![3, 1, 2, 1])), 1);
+    }
+
+    #[test]
+    fn test_t1_argmax() {
+        assert_eq!(Backend::t1_argmax::<4>(s1(vec![3, 1, 3, 2])), 0);
+    }
+
+    #[test]
+    fn test_t2_sum_axis() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_sum_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![5, 7, 9])
+        );
+        assert_eq!(Backend::t2_sum_axis::<2, 3, 2>(a, 1), s1(vec![6, 15]));
+    }
+
+    #[test]
+    fn test_t2_prod_axis() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_prod_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![4, 10, 18])
+        );
+        assert_eq!(Backend::t2_prod_axis::<2, 3, 2>(a, 1), s1(vec![6, 120]));
+    }
+
+    #[test]
+    fn test_t2_min_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_min_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![1, 2, 3])
+        );
+        assert_eq!(Backend::t2_min_axis::<2, 3, 2>(a, 1), s1(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_t2_max_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_max_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![4, 5, 6])
+        );
+        assert_eq!(Backend::t2_max_axis::<2, 3, 2>(a, 1), s1(vec![5, 6]));
+    }
+
+    #[test]
+    fn test_t2_mean_axis() {
+        let a = s2(vec![vec![1.0, 2.0, 3.0], vec![5.0, 6.0, 7.0]]);
+        assert_eq!(
+            Backend::t2_mean_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![3.0, 4.0, 5.0])
+        );
+        assert_eq!(Backend::t2_mean_axis::<2, 3, 2>(a, 1), s1(vec![2.0, 6.0]));
+    }
+
+    #[test]
+    fn test_t2_argmin_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_argmin_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![0, 1, 0])
+        );
+        assert_eq!(Backend::t2_argmin_axis::<2, 3, 2>(a, 1), s1(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_t2_argmax_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_argmax_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![1, 0, 1])
+        );
+        assert_eq!(Backend::t2_argmax_axis::<2, 3, 2>(a, 1), s1(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_t3_sum_axis() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![6, 8], vec![10, 12]])
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![4, 6], vec![12, 14]])
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![3, 7], vec![11, 15]])
+        );
+    }
+
+    #[test]
+    fn test_t3_prod_axis() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![5, 12], vec![21, 32]])
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![3, 8], vec![35, 48]])
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![2, 12], vec![30, 56]])
+        );
+    }
+
+    #[test]
+    fn test_t3_min_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![1, 6], vec![2, 4]])
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![1, 4], vec![2, 6]])
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1, 3], vec![5, 2]])
+        );
+    }
+
+    #[test]
+    fn test_t3_max_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![5, 8], vec![3, 7]])
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![3, 8], vec![5, 7]])
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![8, 4], vec![6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_t3_mean_axis() {
+        let a = s3(vec![
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+            vec![vec![5.0, 6.0], vec![7.0, 8.0]],
+        ]);
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![3.0, 4.0], vec![5.0, 6.0]])
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![2.0, 3.0], vec![6.0, 7.0]])
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1.5, 3.5], vec![5.5, 7.5]])
+        );
+    }
+
+    #[test]
+    fn test_t3_argmin_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![0, 1], vec![1, 0]])
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![0, 1], vec![1, 0]])
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![0, 0], vec![0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_t3_argmax_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![1, 0], vec![0, 1]])
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![1, 0], vec![0, 1]])
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1, 1], vec![1, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_dot() {
+        assert_eq!(
+            Backend::t1_t1_dot::<3>(s1(vec![1, 2, 3]), s1(vec![4, 5, 6])),
+            32
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_matvec() {
+        assert_eq!(
+            Backend::t2_t1_matvec::<2, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3, 4])
+            ),
+            s1(vec![20, 47])
+        );
+    }
+
+    #[test]
+    fn test_t1_t2_vecmat() {
+        assert_eq!(
+            Backend::t1_t2_vecmat::<2, 3>(s1(vec![2, 3]), s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            s1(vec![14, 19, 24])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_matmul() {
+        assert_eq!(
+            Backend::t2_t2_matmul::<2, 3, 2>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s2(vec![vec![2, 3], vec![4, 5], vec![6, 7]])
+            ),
+            s2(vec![vec![28, 34], vec![64, 79]])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_matmul() {
+        assert_eq!(
+            Backend::t3_t2_matmul::<2, 2, 2, 3>(
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ]),
+                s2(vec![vec![1, 0, 2], vec![0, 1, 3]])
+            ),
+            s3(vec![
+                vec![vec![1, 2, 8], vec![3, 4, 18]],
+                vec![vec![5, 6, 28], vec![7, 8, 38]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_bmm() {
+        assert_eq!(
+            Backend::t3_t3_bmm::<2, 2, 2, 1>(
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ]),
+                s3(vec![vec![vec![1], vec![1]], vec![vec![2], vec![-1]]])
+            ),
+            s3(vec![vec![vec![3], vec![7]], vec![vec![4], vec![6]]])
+        );
+    }
+
+    #[test]
+    fn test_permute_rewrites_strides() {
+        let a: Strided<u8, 2> = Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]);
+        let data = a.data.as_ptr();
+        let t = Backend::t2_transpose::<2, 3>(a);
+        assert_eq!(t.data.as_ptr(), data);
+        assert_eq!(t.shape(), [3, 2]);
+        assert_eq!(t.strides(), [1, 3]);
+        assert!(!t.is_contiguous());
+        assert_eq!(t, Strided::new(vec![1, 4, 2, 5, 3, 6], [3, 2]));
+    }
+
+    #[test]
+    fn test_identity_permute() {
+        let a: Strided<u8, 3> = Backend::t3_from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        let permuted = Backend::t3_permute::<2, 2, 2, 2, 2, 2>(a.clone(), (0, 1, 2));
+        assert_eq!(permuted.strides(), a.strides());
+        assert_eq!(permuted.data, a.data);
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut t = Backend::t2_transpose::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!(t.as_slice(), None);
+        assert_eq!(t.make_contiguous(), &[1, 4, 2, 5, 3, 6]);
+        assert!(t.is_contiguous());
+        assert_eq!(t.as_slice(), Some([1, 4, 2, 5, 3, 6].as_slice()));
+    }
+
+    #[test]
+    fn test_get_through_view() {
+        let mut t = Backend::t2_transpose::<2, 3>(Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(*Backend::t2_get::<3, 2>(&t, 2, 1), 6);
+        *Backend::t2_get_mut::<3, 2>(&mut t, 0, 1) = 9;
+        assert_eq!(Backend::t2_into_vec::<3, 2>(t), vec![1, 9, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_kernels_on_views() {
+        let a = Backend::t2_transpose::<2, 2>(Backend::t2_from_array([[1, 2], [3, 4]]));
+        let b = Backend::t2_from_array([[1, 1], [2, 2]]);
+        assert_eq!(
+            Backend::t2_t2_add::<2, 2>(a.clone(), b.clone()),
+            Backend::t2_from_array([[2, 4], [4, 6]])
+        );
+        assert_eq!(
+            Backend::t2_t2_matmul::<2, 2, 2>(a.clone(), b),
+            Backend::t2_from_array([[7, 7], [10, 10]])
+        );
+        assert_eq!(
+            Backend::t2_sum_axis::<2, 2, 2>(a, 1),
+            Backend::t1_from_array([4, 6])
+        );
+    }
+}
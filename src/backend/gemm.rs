@@ -0,0 +1,385 @@
+use std::{
+    ops::{Add, Mul, Sub},
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+
+use crate::num::Zero;
+
+/// Products with at most this many multiply-adds (`m * k * n`) use the naive loop; packing
+/// costs more than it saves below it.
+pub const THRESHOLD: usize = 32 * 32 * 32;
+
+/// Square products of at most this size are not split further by [`strassen`].
+pub const STRASSEN_CUTOFF: usize = 64;
+
+/// Rows of `a` per micro-tile.
+const MR: usize = 4;
+/// Columns of `b` per micro-tile.
+const NR: usize = 8;
+
+static BLOCKING: OnceLock<Blocking> = OnceLock::new();
+
+/// Cache block sizes for [`gemm`]: `a` is packed `mc x kc` at a time and `b` `kc x nc` at a
+/// time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Blocking {
+    pub mc: usize,
+    pub kc: usize,
+    pub nc: usize,
+}
+
+impl Default for Blocking {
+    fn default() -> Self {
+        Self {
+            mc: 64,
+            kc: 256,
+            nc: 1024,
+        }
+    }
+}
+
+impl Blocking {
+    /// Returns the block sizes the backends use: the configured ones, or the defaults.
+    pub fn current() -> Self {
+        *BLOCKING.get_or_init(Self::default)
+    }
+
+    /// Sets the block sizes the backends use. Fails, returning the ones in use, if they were
+    /// already configured or used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any block size is zero.
+    pub fn configure(self) -> Result<(), Self> {
+        self.validate();
+        BLOCKING.set(self).map_err(|_| Self::current())
+    }
+
+    fn validate(self) {
+        assert!(
+            self.mc > 0 && self.kc > 0 && self.nc > 0,
+            "improper blocking"
+        );
+    }
+
+    /// Times a few candidate block sizes on an `f32` product and returns the fastest.
+    pub fn autotune() -> Self {
+        const SIZE: usize = 256;
+        let a: Vec<f32> = (0..SIZE * SIZE).map(|i| (i % 7) as f32).collect();
+        let shape = (SIZE, SIZE, SIZE);
+        let mut best = (Duration::MAX, Self::default());
+        for mc in [32, 64, 128] {
+            for kc in [128, 256, 512] {
+                for nc in [256, 1024, 4096] {
+                    let blocking = Self { mc, kc, nc };
+                    let start = Instant::now();
+                    gemm(&a, &a, shape, blocking);
+                    let elapsed = start.elapsed();
+                    if elapsed < best.0 {
+                        best = (elapsed, blocking);
+                    }
+                }
+            }
+        }
+        best.1
+    }
+}
+
+/// Multiplies the row-major `(m, k)` matrix `a` by the `(k, n)` matrix `b` with the i-j-k
+/// triple loop.
+pub fn naive<T>(a: &[T], b: &[T], (m, k, n): (usize, usize, usize)) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    let mut result = vec![T::zero(); m * n];
+    for i in 0..m {
+        for j in 0..n {
+            for p in 0..k {
+                result[i * n + j] = result[i * n + j] + a[i * k + p] * b[p * n + j];
+            }
+        }
+    }
+    result
+}
+
+/// Multiplies the row-major `(m, k)` matrix `a` by the `(k, n)` matrix `b`, packing both into
+/// cache-sized panels laid out for an `MR x NR` register tile.
+///
+/// # Panics
+///
+/// Panics if any block size in `blocking` is zero.
+pub fn gemm<T>(a: &[T], b: &[T], (m, k, n): (usize, usize, usize), blocking: Blocking) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    assert_eq!(a.len(), m * k);
+    assert_eq!(b.len(), k * n);
+    blocking.validate();
+    let Blocking { mc, kc, nc } = blocking;
+    let mut c = vec![T::zero(); m * n];
+    let mut a_panel = Vec::new();
+    let mut b_panel = Vec::new();
+    for jc in (0..n).step_by(nc) {
+        let nb = nc.min(n - jc);
+        for pc in (0..k).step_by(kc) {
+            let kb = kc.min(k - pc);
+            pack_b(&mut b_panel, b, n, (pc, kb), (jc, nb));
+            for ic in (0..m).step_by(mc) {
+                let mb = mc.min(m - ic);
+                pack_a(&mut a_panel, a, k, (ic, mb), (pc, kb));
+                for (ir, a_strip) in a_panel.chunks_exact(kb * MR).enumerate() {
+                    for (jr, b_strip) in b_panel.chunks_exact(kb * NR).enumerate() {
+                        let tile = kernel(a_strip, b_strip);
+                        let (i0, j0) = (ic + ir * MR, jc + jr * NR);
+                        for (i, row) in tile.iter().enumerate().take(mb - ir * MR) {
+                            let c = &mut c[(i0 + i) * n + j0..];
+                            for (c, &t) in c.iter_mut().zip(row).take(nb - jr * NR) {
+                                *c = *c + t;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    c
+}
+
+/// Multiplies the row-major `(m, k)` matrix `a` by the `(k, n)` matrix `b` with [`naive`], or
+/// past [`THRESHOLD`] with [`gemm`] and the configured block sizes.
+pub fn matmul<T>(a: &[T], b: &[T], (m, k, n): (usize, usize, usize)) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    if m * k * n > THRESHOLD {
+        gemm(a, b, (m, k, n), Blocking::current())
+    } else {
+        naive(a, b, (m, k, n))
+    }
+}
+
+/// Multiplies the row-major `(n, n)` matrices `a` and `b` with Strassen's recursion, falling
+/// back to [`matmul`] once `n <= cutoff`. Odd sizes are padded with a zero row and column.
+///
+/// The recursion subtracts blocks of the operands, so every intermediate difference must be
+/// representable in `T`; unsigned types can overflow where the plain product would not.
+pub fn strassen<T>(a: &[T], b: &[T], n: usize, cutoff: usize) -> Vec<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    assert_eq!(a.len(), n * n);
+    assert_eq!(b.len(), n * n);
+    if n <= cutoff.max(1) {
+        return matmul(a, b, (n, n, n));
+    }
+    if n % 2 == 1 {
+        let c = strassen(&pad(a, n), &pad(b, n), n + 1, cutoff);
+        return c
+            .chunks_exact(n + 1)
+            .take(n)
+            .flat_map(|row| &row[..n])
+            .copied()
+            .collect();
+    }
+
+    let h = n / 2;
+    let [a11, a12, a21, a22] = quadrants(a, n);
+    let [b11, b12, b21, b22] = quadrants(b, n);
+    let add = |a: &[T], b: &[T]| -> Vec<T> { a.iter().zip(b).map(|(&a, &b)| a + b).collect() };
+    let sub = |a: &[T], b: &[T]| -> Vec<T> { a.iter().zip(b).map(|(&a, &b)| a - b).collect() };
+    let mul = |a: &[T], b: &[T]| strassen(a, b, h, cutoff);
+
+    let m1 = mul(&add(&a11, &a22), &add(&b11, &b22));
+    let m2 = mul(&add(&a21, &a22), &b11);
+    let m3 = mul(&a11, &sub(&b12, &b22));
+    let m4 = mul(&a22, &sub(&b21, &b11));
+    let m5 = mul(&add(&a11, &a12), &b22);
+    let m6 = mul(&sub(&a21, &a11), &add(&b11, &b12));
+    let m7 = mul(&sub(&a12, &a22), &add(&b21, &b22));
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+    let mut c = Vec::with_capacity(n * n);
+    for (left, right) in [(&c11, &c12), (&c21, &c22)] {
+        for (left, right) in left.chunks_exact(h).zip(right.chunks_exact(h)) {
+            c.extend_from_slice(left);
+            c.extend_from_slice(right);
+        }
+    }
+    c
+}
+
+/// Appends a zero row and column to the `(n, n)` matrix `a`.
+fn pad<T: Zero + Copy>(a: &[T], n: usize) -> Vec<T> {
+    let mut padded = Vec::with_capacity((n + 1) * (n + 1));
+    for row in a.chunks_exact(n) {
+        padded.extend_from_slice(row);
+        padded.push(T::zero());
+    }
+    padded.resize((n + 1) * (n + 1), T::zero());
+    padded
+}
+
+/// Splits the `(n, n)` matrix `a`, for even `n`, into its top-left, top-right, bottom-left and
+/// bottom-right blocks.
+fn quadrants<T: Copy>(a: &[T], n: usize) -> [Vec<T>; 4] {
+    let h = n / 2;
+    let block = |rows: std::ops::Range<usize>, cols: std::ops::Range<usize>| -> Vec<T> {
+        rows.flat_map(|i| &a[i * n + cols.start..i * n + cols.end])
+            .copied()
+            .collect()
+    };
+    [
+        block(0..h, 0..h),
+        block(0..h, h..n),
+        block(h..n, 0..h),
+        block(h..n, h..n),
+    ]
+}
+
+/// Packs rows `i0..i0 + mb` and columns `p0..p0 + kb` of `a` into strips of `MR` rows, each
+/// stored column by column and zero-padded to a full strip.
+fn pack_a<T: Zero + Copy>(
+    panel: &mut Vec<T>,
+    a: &[T],
+    k: usize,
+    (i0, mb): (usize, usize),
+    (p0, kb): (usize, usize),
+) {
+    panel.clear();
+    for strip in (0..mb).step_by(MR) {
+        for p in p0..p0 + kb {
+            panel.extend((strip..strip + MR).map(|i| {
+                if i < mb {
+                    a[(i0 + i) * k + p]
+                } else {
+                    T::zero()
+                }
+            }));
+        }
+    }
+}
+
+/// Packs rows `p0..p0 + kb` and columns `j0..j0 + nb` of `b` into strips of `NR` columns, each
+/// stored row by row and zero-padded to a full strip.
+fn pack_b<T: Zero + Copy>(
+    panel: &mut Vec<T>,
+    b: &[T],
+    n: usize,
+    (p0, kb): (usize, usize),
+    (j0, nb): (usize, usize),
+) {
+    panel.clear();
+    for strip in (0..nb).step_by(NR) {
+        for p in p0..p0 + kb {
+            panel.extend((strip..strip + NR).map(|j| {
+                if j < nb {
+                    b[p * n + j0 + j]
+                } else {
+                    T::zero()
+                }
+            }));
+        }
+    }
+}
+
+/// Multiplies a packed `MR`-row strip of `a` by a packed `NR`-column strip of `b`.
+fn kernel<T>(a: &[T], b: &[T]) -> [[T; NR]; MR]
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    let mut tile = [[T::zero(); NR]; MR];
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+        for (row, &a) in tile.iter_mut().zip(a) {
+            for (t, &b) in row.iter_mut().zip(b) {
+                *t = *t + a * b;
+            }
+        }
+    }
+    tile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(n: usize, seed: usize) -> Vec<i64> {
+        (0..n).map(|i| ((i * 7 + seed) % 13) as i64 - 6).collect()
+    }
+
+    #[test]
+    fn test_gemm_matches_naive() {
+        let blocking = Blocking {
+            mc: 8,
+            kc: 5,
+            nc: 16,
+        };
+        for (m, k, n) in [
+            (1, 1, 1),
+            (4, 8, 8),
+            (7, 13, 9),
+            (33, 17, 40),
+            (0, 3, 2),
+            (3, 0, 2),
+        ] {
+            let (a, b) = (data(m * k, 1), data(k * n, 2));
+            assert_eq!(gemm(&a, &b, (m, k, n), blocking), naive(&a, &b, (m, k, n)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "improper blocking")]
+    fn test_gemm_zero_blocking() {
+        let blocking = Blocking {
+            mc: 8,
+            kc: 0,
+            nc: 16,
+        };
+        gemm(&data(4, 1), &data(4, 2), (2, 2, 2), blocking);
+    }
+
+    #[test]
+    fn test_autotune() {
+        let blocking = Blocking::autotune();
+        assert!(blocking.mc > 0 && blocking.kc > 0 && blocking.nc > 0);
+        let (m, k, n) = (33, 70, 41);
+        let (a, b) = (data(m * k, 1), data(k * n, 2));
+        assert_eq!(gemm(&a, &b, (m, k, n), blocking), naive(&a, &b, (m, k, n)));
+    }
+
+    #[test]
+    fn test_gemm_floats() {
+        let (m, k, n) = (37, 300, 45);
+        let a: Vec<f64> = (0..m * k).map(|i| (i as f64 * 0.37).sin()).collect();
+        let b: Vec<f64> = (0..k * n).map(|i| (i as f64 * 0.11).cos()).collect();
+        let expected = naive(&a, &b, (m, k, n));
+        for (c, e) in gemm(&a, &b, (m, k, n), Blocking::default())
+            .iter()
+            .zip(expected)
+        {
+            assert!((c - e).abs() < 1e-9, "{c} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_strassen_integers() {
+        for (n, cutoff) in [(1, 1), (2, 1), (5, 1), (8, 2), (13, 3), (33, 4), (64, 8)] {
+            let (a, b) = (data(n * n, 1), data(n * n, 2));
+            assert_eq!(strassen(&a, &b, n, cutoff), naive(&a, &b, (n, n, n)));
+        }
+    }
+
+    #[test]
+    fn test_strassen_floats() {
+        let n = 45;
+        let a: Vec<f64> = (0..n * n).map(|i| (i as f64 * 0.37).sin()).collect();
+        let b: Vec<f64> = (0..n * n).map(|i| (i as f64 * 0.11).cos()).collect();
+        let expected = naive(&a, &b, (n, n, n));
+        for (c, e) in strassen(&a, &b, n, 4).iter().zip(expected) {
+            assert!((c - e).abs() < 1e-9, "{c} != {e}");
+        }
+    }
+}
@@ -1,80 +1,880 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use crate::tensor::{Dimension, Index};
+use crate::{
+    num::{One, Zero},
+    tensor::{Dimension, Index},
+};
 
+pub mod array;
 pub mod basic;
+pub mod flat;
+pub mod gemm;
+pub mod simd;
+pub mod threaded;
 
-pub type AutoSelectBackend = basic::Backend;
+pub type AutoSelectBackend = flat::Backend;
 
 pub trait Backend<T> {
     type Index: From<Index>;
     type Dimension: From<Dimension>;
-    type T0Repr;
-    type T1Repr;
-    type T2Repr;
-    type T3Repr;
+    type T0Repr: Clone;
+    type T1Repr<const D0: usize>: Clone;
+    type T2Repr<const D0: usize, const D1: usize>: Clone;
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize>: Clone;
+
+    fn t0_from_value(v: T) -> Self::T0Repr;
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0>;
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1>;
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2>;
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0>;
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1>;
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2>;
+    fn t0_into_value(a: Self::T0Repr) -> T;
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T>;
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T>;
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T>;
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0>;
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1>;
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2>;
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0>;
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1>;
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2>;
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0>;
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1>;
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2>;
+
+    fn t0_get(a: &Self::T0Repr) -> &T;
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T;
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: Self::Index) -> &T;
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: Self::Index) -> &mut T;
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: Self::Index,
+        j: Self::Index,
+    ) -> &T;
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: Self::Index,
+        j: Self::Index,
+    ) -> &mut T;
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: Self::Index,
+        j: Self::Index,
+        k: Self::Index,
+    ) -> &T;
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: Self::Index,
+        j: Self::Index,
+        k: Self::Index,
+    ) -> &mut T;
+
+    /// # Safety
+    ///
+    /// `i` must be in bounds for `a`.
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: Self::Index) -> &T;
+    /// # Safety
+    ///
+    /// `i` must be in bounds for `a`.
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(
+        a: &mut Self::T1Repr<D0>,
+        i: Self::Index,
+    ) -> &mut T;
+    /// # Safety
+    ///
+    /// `(i, j)` must be in bounds for `a`.
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: Self::Index,
+        j: Self::Index,
+    ) -> &T;
+    /// # Safety
+    ///
+    /// `(i, j)` must be in bounds for `a`.
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: Self::Index,
+        j: Self::Index,
+    ) -> &mut T;
+    /// # Safety
+    ///
+    /// `(i, j, k)` must be in bounds for `a`.
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: Self::Index,
+        j: Self::Index,
+        k: Self::Index,
+    ) -> &T;
+    /// # Safety
+    ///
+    /// `(i, j, k)` must be in bounds for `a`.
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: Self::Index,
+        j: Self::Index,
+        k: Self::Index,
+    ) -> &mut T;
 
     fn t0_zero() -> Self::T0Repr
     where
-        T: From<u8>;
+        T: Zero;
     fn t0_one() -> Self::T0Repr
     where
-        T: From<u8>;
-    fn t1_zeros(d0: Self::Index) -> Self::T1Repr
+        T: One;
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
     where
-        T: From<u8> + Copy;
-    fn t1_ones(d0: Self::Index) -> Self::T1Repr
+        T: Zero + Copy;
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
     where
-        T: From<u8> + Copy;
-    fn t2_zeros(d0: Self::Index, d1: Self::Index) -> Self::T2Repr
+        T: One + Copy;
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
     where
-        T: From<u8> + Copy;
-    fn t2_ones(d0: Self::Index, d1: Self::Index) -> Self::T2Repr
+        T: Zero + Copy;
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
     where
-        T: From<u8> + Copy;
-    fn t3_ones(d0: Self::Index, d1: Self::Index, d2: Self::Index) -> Self::T3Repr
+        T: One + Copy;
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
     where
-        T: From<u8> + Copy;
-    fn t3_zeros(d0: Self::Index, d1: Self::Index, d2: Self::Index) -> Self::T3Repr
+        T: One + Copy;
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
     where
-        T: From<u8> + Copy;
+        T: Zero + Copy;
 
-    fn t2_identity(d: Self::Index) -> Self::T2Repr
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
     where
-        T: From<u8> + Copy;
-    fn t2_transpose(a: Self::T2Repr) -> Self::T2Repr
+        T: Zero + One + Copy;
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
     where
-        T: From<u8> + Copy;
-    fn t3_permute(
-        a: Self::T3Repr,
+        T: Zero + Copy;
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (Self::Dimension, Self::Dimension),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy;
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
         p: (Self::Dimension, Self::Dimension, Self::Dimension),
-    ) -> Self::T3Repr
+    ) -> Self::T3Repr<RD0, RD1, RD2>
     where
-        T: From<u8> + Copy;
+        T: Zero + Copy;
+
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0>;
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>;
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: Self::Dimension,
+    ) -> Self::T3Repr<RD0, RD1, RD2>;
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1>;
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2>;
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        at: Self::Index,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>);
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        at: Self::Index,
+        axis: Self::Dimension,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>);
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        at: Self::Index,
+        axis: Self::Dimension,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>);
 
     fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
     where
         T: Add<Output = T>;
-    fn t1_t0_add(a: Self::T1Repr, b: Self::T0Repr) -> Self::T1Repr
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
     where
         T: Add<Output = T> + Copy;
-    fn t1_t1_add(a: Self::T1Repr, b: Self::T1Repr) -> Self::T1Repr
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
     where
         T: Add<Output = T>;
-    fn t2_t0_add(a: Self::T2Repr, b: Self::T0Repr) -> Self::T2Repr
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
     where
         T: Add<Output = T> + Copy;
-    fn t2_t1_add(a: Self::T2Repr, b: Self::T1Repr, along: Self::Dimension) -> Self::T2Repr
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
     where
         T: Add<Output = T> + Copy;
-    fn t2_t2_add(a: Self::T2Repr, b: Self::T2Repr) -> Self::T2Repr
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T>;
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy;
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy;
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy;
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
     where
         T: Add<Output = T>;
 
-    fn t1_t1_dot(a: Self::T1Repr, b: Self::T1Repr) -> Self::T0Repr
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>;
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>;
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>;
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy;
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>;
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>;
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy;
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>;
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy;
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
     where
-        T: Add<Output = T> + Mul<Output = T> + From<u8>;
-    fn t2_t2_matmul(a: Self::T2Repr, b: Self::T2Repr) -> Self::T2Repr
+        T: Mul<Output = T> + Copy;
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>;
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy;
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy;
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy;
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>;
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>;
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy;
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>;
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy;
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy;
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>;
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy;
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy;
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy;
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>;
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>;
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>;
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>;
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: Self::Dimension,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy;
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>;
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
     where
-        T: Add<Output = T> + Mul<Output = T> + From<u8> + Copy;
+        T: Neg<Output = T>;
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>;
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>;
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>;
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr;
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0>;
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1>;
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2>;
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr;
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0>;
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1>;
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2>;
+
+    /// Folds the elements in row-major order.
+    fn t0_fold<U>(a: Self::T0Repr, init: U, f: impl FnMut(U, T) -> U) -> U;
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U;
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U;
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U;
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy;
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy;
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy;
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy;
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy;
+    fn t1_argmin<const D0: usize>(
+        a: <Self as Backend<T>>::T1Repr<D0>,
+    ) -> <Self as Backend<Index>>::T0Repr
+    where
+        T: PartialOrd,
+        Self: Backend<Index>;
+    fn t1_argmax<const D0: usize>(
+        a: <Self as Backend<T>>::T1Repr<D0>,
+    ) -> <Self as Backend<Index>>::T0Repr
+    where
+        T: PartialOrd,
+        Self: Backend<Index>;
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: Self::Dimension,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy;
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: Self::Dimension,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy;
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: Self::Dimension,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy;
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: Self::Dimension,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy;
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: Self::Dimension,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy;
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: <Self as Backend<T>>::T2Repr<D0, D1>,
+        axis: <Self as Backend<T>>::Dimension,
+    ) -> <Self as Backend<Index>>::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+        Self: Backend<Index>;
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: <Self as Backend<T>>::T2Repr<D0, D1>,
+        axis: <Self as Backend<T>>::Dimension,
+    ) -> <Self as Backend<Index>>::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+        Self: Backend<Index>;
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy;
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy;
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy;
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy;
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: Self::Dimension,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy;
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: <Self as Backend<T>>::T3Repr<D0, D1, D2>,
+        axis: <Self as Backend<T>>::Dimension,
+    ) -> <Self as Backend<Index>>::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+        Self: Backend<Index>;
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: <Self as Backend<T>>::T3Repr<D0, D1, D2>,
+        axis: <Self as Backend<T>>::Dimension,
+    ) -> <Self as Backend<Index>>::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+        Self: Backend<Index>;
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero;
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy;
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy;
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy;
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy;
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy;
+}
+
+/// Converts the element count `n` to `T`, as the divisor of a mean.
+///
+/// # Panics
+///
+/// Panics if `n` does not fit in `T`, such as 256 elements of `u8`.
+pub(crate) fn count<T: Zero + One + Add<Output = T> + Copy>(n: usize) -> T {
+    T::from_usize(n).unwrap_or_else(|| {
+        panic!(
+            "cannot count {n} elements in {}",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+// Reductions over the elements of a whole tensor or of one lane, shared by every backend.
+
+pub(crate) fn sum<T: Add<Output = T> + Zero + Copy>(a: &[T]) -> T {
+    a.iter().fold(T::zero(), |acc, a| acc + *a)
+}
+
+pub(crate) fn prod<T: Mul<Output = T> + One + Copy>(a: &[T]) -> T {
+    a.iter().fold(T::one(), |acc, a| acc * *a)
+}
+
+pub(crate) fn min<T: PartialOrd + Copy>(a: &[T]) -> T {
+    a.iter()
+        .copied()
+        .reduce(|acc, a| if a < acc { a } else { acc })
+        .expect("cannot reduce an empty tensor")
+}
+
+pub(crate) fn max<T: PartialOrd + Copy>(a: &[T]) -> T {
+    a.iter()
+        .copied()
+        .reduce(|acc, a| if a > acc { a } else { acc })
+        .expect("cannot reduce an empty tensor")
+}
+
+pub(crate) fn mean<T: Add<Output = T> + Div<Output = T> + Zero + One + Copy>(a: &[T]) -> T {
+    sum(a) / count(a.len())
+}
+
+pub(crate) fn argmin<T: PartialOrd>(a: &[T]) -> usize {
+    a.iter()
+        .enumerate()
+        .reduce(|acc, a| if a.1 < acc.1 { a } else { acc })
+        .expect("cannot reduce an empty tensor")
+        .0
+}
+
+pub(crate) fn argmax<T: PartialOrd>(a: &[T]) -> usize {
+    a.iter()
+        .enumerate()
+        .reduce(|acc, a| if a.1 > acc.1 { a } else { acc })
+        .expect("cannot reduce an empty tensor")
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count::<u8>(0), 0);
+        assert_eq!(count::<i32>(1), 1);
+        assert_eq!(count::<u16>(1000), 1000);
+        assert_eq!(count::<f32>(12345), 12345.0);
+        assert_eq!(count::<u8>(255), 255);
+        assert_eq!(mean(&[1u8; 255]), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot count 256 elements in u8")]
+    fn test_mean_count_overflow() {
+        mean(&[0u8; 256]);
+    }
 }
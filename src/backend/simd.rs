@@ -0,0 +1,1593 @@
+use std::{
+    any::Any,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    simd::{
+        num::{SimdFloat, SimdInt},
+        Simd,
+    },
+};
+
+use crate::backend::{
+    flat::{self, Strided},
+    Backend as BackendTrait,
+};
+use crate::num::{One, Zero};
+
+type Flat = flat::Backend;
+
+const LANES: usize = 8;
+
+/// Runs `t*_t*_add`, `t1_t1_dot` and `t2_t2_matmul` on SIMD lanes when `T` is `f32`, `f64` or
+/// `i32`, and everything else through the flat backend's scalar kernels.
+///
+/// Integer lanes wrap on overflow instead of panicking.
+#[derive(Clone, Debug)]
+pub struct Backend {}
+
+/// Element types with SIMD kernels.
+trait Lanes: Sized {
+    fn add(a: &[Self], b: &[Self]) -> Vec<Self>;
+    fn dot(a: &[Self], b: &[Self]) -> Self;
+    fn matmul(a: &[Self], b: &[Self], shape: (usize, usize, usize)) -> Vec<Self>;
+}
+
+/// `$add` and `$mul` are the scalar operations for the tails, matching the lanes' overflow
+/// behaviour.
+macro_rules! lanes {
+    ($($t:ty => $add:path, $mul:path);*) => {$(
+        impl Lanes for $t {
+            fn add(a: &[Self], b: &[Self]) -> Vec<Self> {
+                let mut result = Vec::with_capacity(a.len());
+                let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+                let (a_tail, b_tail) = (a_chunks.remainder(), b_chunks.remainder());
+                for (a, b) in a_chunks.zip(b_chunks) {
+                    let sum = Simd::<$t, LANES>::from_slice(a) + Simd::from_slice(b);
+                    result.extend_from_slice(sum.as_array());
+                }
+                result.extend(a_tail.iter().zip(b_tail).map(|(&a, &b)| $add(a, b)));
+                result
+            }
+
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+                let (a_tail, b_tail) = (a_chunks.remainder(), b_chunks.remainder());
+                let mut acc = Simd::<$t, LANES>::splat(0 as $t);
+                for (a, b) in a_chunks.zip(b_chunks) {
+                    acc += Simd::from_slice(a) * Simd::from_slice(b);
+                }
+                a_tail
+                    .iter()
+                    .zip(b_tail)
+                    .fold(acc.reduce_sum(), |acc, (&a, &b)| $add(acc, $mul(a, b)))
+            }
+
+            fn matmul(a: &[Self], b: &[Self], (m, k, n): (usize, usize, usize)) -> Vec<Self> {
+                let mut result = vec![0 as $t; m * n];
+                for (a, row) in a.chunks_exact(k).zip(result.chunks_exact_mut(n)) {
+                    for (&a, b) in a.iter().zip(b.chunks_exact(n)) {
+                        let splat = Simd::<$t, LANES>::splat(a);
+                        let mut row = row.chunks_exact_mut(LANES);
+                        let mut b = b.chunks_exact(LANES);
+                        for (row, b) in row.by_ref().zip(b.by_ref()) {
+                            let sum = Simd::from_slice(row) + splat * Simd::from_slice(b);
+                            row.copy_from_slice(sum.as_array());
+                        }
+                        for (row, &b) in row.into_remainder().iter_mut().zip(b.remainder()) {
+                            *row = $add(*row, $mul(a, b));
+                        }
+                    }
+                }
+                result
+            }
+        }
+    )*};
+}
+
+lanes!(
+    f32 => Add::add, Mul::mul;
+    f64 => Add::add, Mul::mul;
+    i32 => i32::wrapping_add, i32::wrapping_mul
+);
+
+/// Runs `kernel` on `a` and `b` if they are `Vec<E>`, converting its result back to `R`.
+fn with_lanes<E: 'static, S: 'static, R: 'static>(
+    a: &dyn Any,
+    b: &dyn Any,
+    kernel: impl FnOnce(&[E], &[E]) -> S,
+) -> Option<R> {
+    let a = a.downcast_ref::<Vec<E>>()?;
+    let b = b.downcast_ref::<Vec<E>>()?;
+    let mut result = Some(kernel(a, b));
+    (&mut result as &mut dyn Any)
+        .downcast_mut::<Option<R>>()?
+        .take()
+}
+
+/// Evaluates `kernel` with the [`Lanes`] impl for the element type, or returns `None` if it
+/// has none.
+macro_rules! simd {
+    ($a:expr, $b:expr, |$x:ident, $y:ident| $kernel:expr) => {
+        with_lanes::<f32, _, _>($a, $b, |$x, $y| $kernel)
+            .or_else(|| with_lanes::<f64, _, _>($a, $b, |$x, $y| $kernel))
+            .or_else(|| with_lanes::<i32, _, _>($a, $b, |$x, $y| $kernel))
+    };
+}
+
+fn add<T: Add<Output = T> + Clone + 'static, const N: usize>(
+    a: Strided<T, N>,
+    b: Strided<T, N>,
+) -> Strided<T, N> {
+    assert_eq!(a.shape(), b.shape());
+    let (a, b) = (a.into_contiguous(), b.into_contiguous());
+    let shape = a.shape();
+    let data = simd!(&a.data, &b.data, |a, b| Lanes::add(a, b))
+        .unwrap_or_else(|| a.data.into_iter().zip(b.data).map(|(a, b)| a + b).collect());
+    Strided::new(data, shape)
+}
+
+impl<T: Clone + 'static> BackendTrait<T> for Backend {
+    type Index = usize;
+    type Dimension = usize;
+    type T0Repr = T;
+    type T1Repr<const D0: usize> = Strided<T, 1>;
+    type T2Repr<const D0: usize, const D1: usize> = Strided<T, 2>;
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize> = Strided<T, 3>;
+
+    fn t0_from_value(v: T) -> Self::T0Repr {
+        Flat::t0_from_value(v)
+    }
+
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0> {
+        Flat::t1_from_array::<D0>(a)
+    }
+
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1> {
+        Flat::t2_from_array::<D0, D1>(a)
+    }
+
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_from_array::<D0, D1, D2>(a)
+    }
+
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0> {
+        Flat::t1_from_vec::<D0>(v)
+    }
+
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1> {
+        Flat::t2_from_vec::<D0, D1>(v)
+    }
+
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_from_vec::<D0, D1, D2>(v)
+    }
+
+    fn t0_into_value(a: Self::T0Repr) -> T {
+        Flat::t0_into_value(a)
+    }
+
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T> {
+        Flat::t1_into_vec::<D0>(a)
+    }
+
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T> {
+        Flat::t2_into_vec::<D0, D1>(a)
+    }
+
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T> {
+        Flat::t3_into_vec::<D0, D1, D2>(a)
+    }
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0> {
+        Flat::t1_reshape1::<D0, RD0>(a)
+    }
+
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Flat::t1_reshape2::<D0, RD0, RD1>(a)
+    }
+
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Flat::t1_reshape3::<D0, RD0, RD1, RD2>(a)
+    }
+
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0> {
+        Flat::t2_reshape1::<D0, D1, RD0>(a)
+    }
+
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Flat::t2_reshape2::<D0, D1, RD0, RD1>(a)
+    }
+
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Flat::t2_reshape3::<D0, D1, RD0, RD1, RD2>(a)
+    }
+
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0> {
+        Flat::t3_reshape1::<D0, D1, D2, RD0>(a)
+    }
+
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Flat::t3_reshape2::<D0, D1, D2, RD0, RD1>(a)
+    }
+
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Flat::t3_reshape3::<D0, D1, D2, RD0, RD1, RD2>(a)
+    }
+
+    fn t0_get(a: &Self::T0Repr) -> &T {
+        Flat::t0_get(a)
+    }
+
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T {
+        Flat::t0_get_mut(a)
+    }
+
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        Flat::t1_get::<D0>(a, i)
+    }
+
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        Flat::t1_get_mut::<D0>(a, i)
+    }
+
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        Flat::t2_get::<D0, D1>(a, i, j)
+    }
+
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        Flat::t2_get_mut::<D0, D1>(a, i, j)
+    }
+
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        Flat::t3_get::<D0, D1, D2>(a, i, j, k)
+    }
+
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        Flat::t3_get_mut::<D0, D1, D2>(a, i, j, k)
+    }
+
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        Flat::t1_get_unchecked::<D0>(a, i)
+    }
+
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        Flat::t1_get_unchecked_mut::<D0>(a, i)
+    }
+
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        Flat::t2_get_unchecked::<D0, D1>(a, i, j)
+    }
+
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        Flat::t2_get_unchecked_mut::<D0, D1>(a, i, j)
+    }
+
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        Flat::t3_get_unchecked::<D0, D1, D2>(a, i, j, k)
+    }
+
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        Flat::t3_get_unchecked_mut::<D0, D1, D2>(a, i, j, k)
+    }
+
+    fn t0_zero() -> Self::T0Repr
+    where
+        T: Zero,
+    {
+        Flat::t0_zero()
+    }
+
+    fn t0_one() -> Self::T0Repr
+    where
+        T: One,
+    {
+        Flat::t0_one()
+    }
+
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t1_zeros::<D0>()
+    }
+
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: One + Copy,
+    {
+        Flat::t1_ones::<D0>()
+    }
+
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_zeros::<D0, D1>()
+    }
+
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: One + Copy,
+    {
+        Flat::t2_ones::<D0, D1>()
+    }
+
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: One + Copy,
+    {
+        Flat::t3_ones::<D0, D1, D2>()
+    }
+
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t3_zeros::<D0, D1, D2>()
+    }
+
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
+    where
+        T: Zero + One + Copy,
+    {
+        Flat::t2_identity::<D0>()
+    }
+
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_transpose::<D0, D1>(a)
+    }
+
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (usize, usize),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_permute::<D0, D1, RD0, RD1>(a, p)
+    }
+
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        p: (usize, usize, usize),
+    ) -> Self::T3Repr<RD0, RD1, RD2>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t3_permute::<D0, D1, D2, RD0, RD1, RD2>(a, p)
+    }
+
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0> {
+        Flat::t1_concat::<D0, OD0, RD0>(a, b)
+    }
+
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Flat::t2_concat::<D0, D1, OD0, OD1, RD0, RD1>(a, b, axis)
+    }
+
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: usize,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Flat::t3_concat::<D0, D1, D2, OD0, OD1, OD2, RD0, RD1, RD2>(a, b, axis)
+    }
+
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1> {
+        Flat::t2_stack::<D0, D1>(a)
+    }
+
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_stack::<D0, D1, D2>(a)
+    }
+
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        at: usize,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>) {
+        Flat::t1_split::<D0, LD0, RD0>(a, at)
+    }
+
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>) {
+        Flat::t2_split::<D0, D1, LD0, LD1, RD0, RD1>(a, at, axis)
+    }
+
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>) {
+        Flat::t3_split::<D0, D1, D2, LD0, LD1, LD2, RD0, RD1, RD2>(a, at, axis)
+    }
+
+    fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Add<Output = T>,
+    {
+        Flat::t0_t0_add(a, b)
+    }
+
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t1_t0_add::<D0>(a, b)
+    }
+
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T>,
+    {
+        add(a, b)
+    }
+
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t2_t0_add::<D0, D1>(a, b)
+    }
+
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t2_t1_add::<D0, D1, OD0>(a, b, along)
+    }
+
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T>,
+    {
+        add(a, b)
+    }
+
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t3_t0_add::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t3_t1_add::<D0, D1, D2, OD0>(a, b, along)
+    }
+
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Flat::t3_t2_add::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T>,
+    {
+        add(a, b)
+    }
+
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>,
+    {
+        Flat::t0_t0_sub(a, b)
+    }
+
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t1_t0_sub::<D0>(a, b)
+    }
+
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>,
+    {
+        Flat::t1_t1_sub::<D0>(a, b)
+    }
+
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t2_t0_sub::<D0, D1>(a, b)
+    }
+
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t2_t1_sub::<D0, D1, OD0>(a, b, along)
+    }
+
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>,
+    {
+        Flat::t2_t2_sub::<D0, D1>(a, b)
+    }
+
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t3_t0_sub::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t3_t1_sub::<D0, D1, D2, OD0>(a, b, along)
+    }
+
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Flat::t3_t2_sub::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>,
+    {
+        Flat::t3_t3_sub::<D0, D1, D2>(a, b)
+    }
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>,
+    {
+        Flat::t0_t0_mul(a, b)
+    }
+
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t1_t0_mul::<D0>(a, b)
+    }
+
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>,
+    {
+        Flat::t1_t1_mul::<D0>(a, b)
+    }
+
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t2_t0_mul::<D0, D1>(a, b)
+    }
+
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t2_t1_mul::<D0, D1, OD0>(a, b, along)
+    }
+
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>,
+    {
+        Flat::t2_t2_mul::<D0, D1>(a, b)
+    }
+
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t3_t0_mul::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t3_t1_mul::<D0, D1, D2, OD0>(a, b, along)
+    }
+
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Flat::t3_t2_mul::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>,
+    {
+        Flat::t3_t3_mul::<D0, D1, D2>(a, b)
+    }
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>,
+    {
+        Flat::t0_t0_div(a, b)
+    }
+
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t1_t0_div::<D0>(a, b)
+    }
+
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>,
+    {
+        Flat::t1_t1_div::<D0>(a, b)
+    }
+
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t2_t0_div::<D0, D1>(a, b)
+    }
+
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t2_t1_div::<D0, D1, OD0>(a, b, along)
+    }
+
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>,
+    {
+        Flat::t2_t2_div::<D0, D1>(a, b)
+    }
+
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t3_t0_div::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t3_t1_div::<D0, D1, D2, OD0>(a, b, along)
+    }
+
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Flat::t3_t2_div::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>,
+    {
+        Flat::t3_t3_div::<D0, D1, D2>(a, b)
+    }
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>,
+    {
+        Flat::t0_t0_rem(a, b)
+    }
+
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t1_t0_rem::<D0>(a, b)
+    }
+
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>,
+    {
+        Flat::t1_t1_rem::<D0>(a, b)
+    }
+
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t2_t0_rem::<D0, D1>(a, b)
+    }
+
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t2_t1_rem::<D0, D1, OD0>(a, b, along)
+    }
+
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>,
+    {
+        Flat::t2_t2_rem::<D0, D1>(a, b)
+    }
+
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t3_t0_rem::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t3_t1_rem::<D0, D1, D2, OD0>(a, b, along)
+    }
+
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Flat::t3_t2_rem::<D0, D1, D2>(a, b)
+    }
+
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>,
+    {
+        Flat::t3_t3_rem::<D0, D1, D2>(a, b)
+    }
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Neg<Output = T>,
+    {
+        Flat::t0_neg(a)
+    }
+
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>,
+    {
+        Flat::t1_neg::<D0>(a)
+    }
+
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>,
+    {
+        Flat::t2_neg::<D0, D1>(a)
+    }
+
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>,
+    {
+        Flat::t3_neg::<D0, D1, D2>(a)
+    }
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr {
+        Flat::t0_map(a, f)
+    }
+
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0> {
+        Flat::t1_map::<D0>(a, f)
+    }
+
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        Flat::t2_map::<D0, D1>(a, f)
+    }
+
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_map::<D0, D1, D2>(a, f)
+    }
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr {
+        Flat::t0_zip_with(a, b, f)
+    }
+
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0> {
+        Flat::t1_zip_with::<D0>(a, b, f)
+    }
+
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        Flat::t2_zip_with::<D0, D1>(a, b, f)
+    }
+
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_zip_with::<D0, D1, D2>(a, b, f)
+    }
+
+    fn t0_fold<U>(a: Self::T0Repr, init: U, f: impl FnMut(U, T) -> U) -> U {
+        Flat::t0_fold(a, init, f)
+    }
+
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U {
+        Flat::t1_fold::<D0, U>(a, init, f)
+    }
+
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        Flat::t2_fold::<D0, D1, U>(a, init, f)
+    }
+
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        Flat::t3_fold::<D0, D1, D2, U>(a, init, f)
+    }
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Flat::t1_sum::<D0>(a)
+    }
+
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Flat::t1_prod::<D0>(a)
+    }
+
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t1_min::<D0>(a)
+    }
+
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t1_max::<D0>(a)
+    }
+
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Flat::t1_mean::<D0>(a)
+    }
+
+    fn t1_argmin<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        Flat::t1_argmin::<D0>(a)
+    }
+
+    fn t1_argmax<const D0: usize>(a: Self::T1Repr<D0>) -> usize
+    where
+        T: PartialOrd,
+    {
+        Flat::t1_argmax::<D0>(a)
+    }
+
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Flat::t2_sum_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Flat::t2_prod_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t2_min_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t2_max_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Flat::t2_mean_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Strided<usize, 1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t2_argmin_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Strided<usize, 1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t2_argmax_axis::<D0, D1, RD0>(a, axis)
+    }
+
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Flat::t3_sum_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Flat::t3_prod_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t3_min_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t3_max_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Flat::t3_mean_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Strided<usize, 2>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t3_argmin_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Strided<usize, 2>
+    where
+        T: PartialOrd + Copy,
+    {
+        Flat::t3_argmax_axis::<D0, D1, D2, RD0, RD1>(a, axis)
+    }
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        assert_eq!(a.shape(), b.shape());
+        let (a, b) = (a.into_contiguous(), b.into_contiguous());
+        simd!(&a.data, &b.data, |a, b| Lanes::dot(a, b))
+            .unwrap_or_else(|| Flat::t1_t1_dot::<D0>(a, b))
+    }
+
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        Flat::t2_t1_matvec::<D0, D1>(a, b)
+    }
+
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        Flat::t1_t2_vecmat::<D0, D1>(a, b)
+    }
+
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let ([m, k], [bk, n]) = (a.shape(), b.shape());
+        assert_eq!(k, bk);
+        let (a, b) = (a.into_contiguous(), b.into_contiguous());
+        match simd!(&a.data, &b.data, |a, b| Lanes::matmul(a, b, (m, k, n))) {
+            Some(data) => Strided::new(data, [m, n]),
+            None => Flat::t2_t2_matmul::<D0, D1, OD1>(a, b),
+        }
+    }
+
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        Flat::t3_t2_matmul::<D0, D1, D2, OD1>(a, b)
+    }
+
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        Flat::t3_t3_bmm::<D0, D1, D2, OD2>(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::backend::basic;
+
+    type Basic = basic::Backend;
+
+    #[test]
+    fn test_t1_t1_add() {
+        assert_eq!(
+            Backend::t1_t1_add::<3>(
+                Backend::t1_from_array([1.0, 2.0, 3.0]),
+                Backend::t1_from_array([0.5, 0.5, 0.5])
+            ),
+            Backend::t1_from_array([1.5, 2.5, 3.5])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_add() {
+        assert_eq!(
+            Backend::t2_t2_add::<2, 2>(
+                Backend::t2_from_array([[1, 2], [3, 4]]),
+                Backend::t2_from_array([[1, 1], [2, 2]])
+            ),
+            Backend::t2_from_array([[2, 3], [5, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_add() {
+        assert_eq!(
+            Backend::t3_t3_add::<2, 1, 2>(
+                Backend::t3_from_array([[[1u8, 2]], [[3, 4]]]),
+                Backend::t3_from_array([[[1, 1]], [[2, 2]]])
+            ),
+            Backend::t3_from_array([[[2, 3]], [[5, 6]]])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_dot() {
+        assert_eq!(
+            Backend::t1_t1_dot::<3>(
+                Backend::t1_from_array([1.0, 2.0, 3.0]),
+                Backend::t1_from_array([4.0, 5.0, 6.0])
+            ),
+            32.0
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_matmul() {
+        assert_eq!(
+            Backend::t2_t2_matmul::<2, 3, 2>(
+                Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]),
+                Backend::t2_from_array([[1, 0], [0, 1], [1, 1]])
+            ),
+            Backend::t2_from_array([[4, 5], [10, 11]])
+        );
+    }
+
+    #[test]
+    fn test_kernels_on_views() {
+        let a = Backend::t2_transpose::<2, 9>(Backend::t2_from_vec::<2, 9>((0..18).collect()));
+        let b = Backend::t2_from_vec::<9, 2>((0..18).collect());
+        assert_eq!(
+            Backend::t2_into_vec::<9, 2>(Backend::t2_t2_add::<9, 2>(a.clone(), b)),
+            (0..9).flat_map(|i| [3 * i, 3 * i + 10]).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Backend::t2_t2_matmul::<9, 2, 1>(a, Backend::t2_from_array([[1], [1]])),
+            Backend::t2_from_vec::<9, 1>((0..9).map(|i| 2 * i + 9).collect())
+        );
+    }
+
+    /// Small integers, so float sums are exact whatever order they are added in.
+    fn data<T: From<i8>>(n: usize, seed: usize) -> Vec<T> {
+        (0..n)
+            .map(|i| (((i * 7 + seed) % 13) as i8 - 6).into())
+            .collect()
+    }
+
+    /// Checks the SIMD kernels against `basic::Backend` on `M x K` and `K x N` operands.
+    fn differential<T, const M: usize, const K: usize, const N: usize>()
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + From<i8> + Copy + PartialEq + Debug,
+        T: 'static,
+    {
+        let (a, b, c) = (
+            data::<T>(M * K, 1),
+            data::<T>(M * K, 2),
+            data::<T>(K * N, 3),
+        );
+
+        assert_eq!(
+            Backend::t2_into_vec::<M, K>(Backend::t2_t2_add::<M, K>(
+                Backend::t2_from_vec::<M, K>(a.clone()),
+                Backend::t2_from_vec::<M, K>(b.clone())
+            )),
+            Basic::t2_into_vec::<M, K>(Basic::t2_t2_add::<M, K>(
+                Basic::t2_from_vec::<M, K>(a.clone()),
+                Basic::t2_from_vec::<M, K>(b.clone())
+            ))
+        );
+        assert_eq!(
+            Backend::t1_t1_dot::<K>(
+                Backend::t1_from_vec::<K>(a[..K].to_vec()),
+                Backend::t1_from_vec::<K>(b[..K].to_vec())
+            ),
+            Basic::t1_t1_dot::<K>(
+                Basic::t1_from_vec::<K>(a[..K].to_vec()),
+                Basic::t1_from_vec::<K>(b[..K].to_vec())
+            )
+        );
+        assert_eq!(
+            Backend::t2_into_vec::<M, N>(Backend::t2_t2_matmul::<M, K, N>(
+                Backend::t2_from_vec::<M, K>(a.clone()),
+                Backend::t2_from_vec::<K, N>(c.clone())
+            )),
+            Basic::t2_into_vec::<M, N>(Basic::t2_t2_matmul::<M, K, N>(
+                Basic::t2_from_vec::<M, K>(a),
+                Basic::t2_from_vec::<K, N>(c)
+            ))
+        );
+    }
+
+    fn differential_shapes<T>()
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + From<i8> + Copy + PartialEq + Debug,
+        T: 'static,
+    {
+        differential::<T, 1, 1, 1>();
+        differential::<T, 1, 8, 8>();
+        differential::<T, 3, 7, 9>();
+        differential::<T, 4, 16, 17>();
+        differential::<T, 5, 37, 31>();
+    }
+
+    #[test]
+    fn test_differential_f32() {
+        differential_shapes::<f32>();
+    }
+
+    #[test]
+    fn test_differential_f64() {
+        differential_shapes::<f64>();
+    }
+
+    #[test]
+    fn test_differential_i32() {
+        differential_shapes::<i32>();
+
+        // Nine elements, so the last one goes through the scalar tail.
+        let (a, b) = (vec![i32::MAX; 9], vec![2; 9]);
+        let product = i32::MAX.wrapping_mul(2);
+        assert_eq!(
+            Backend::t1_into_vec::<9>(Backend::t1_t1_add::<9>(
+                Backend::t1_from_vec::<9>(a.clone()),
+                Backend::t1_from_vec::<9>(b.clone())
+            )),
+            vec![i32::MAX.wrapping_add(2); 9]
+        );
+        assert_eq!(
+            Backend::t1_t1_dot::<9>(
+                Backend::t1_from_vec::<9>(a.clone()),
+                Backend::t1_from_vec::<9>(b.clone())
+            ),
+            product.wrapping_mul(9)
+        );
+        assert_eq!(
+            Backend::t2_into_vec::<1, 9>(Backend::t2_t2_matmul::<1, 1, 9>(
+                Backend::t2_from_vec::<1, 1>(vec![2]),
+                Backend::t2_from_vec::<1, 9>(a)
+            )),
+            vec![product; 9]
+        );
+    }
+
+    #[test]
+    fn test_differential_scalar_fallback() {
+        differential_shapes::<i64>();
+        differential_shapes::<i16>();
+    }
+}
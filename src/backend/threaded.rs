@@ -0,0 +1,3213 @@
+use std::{
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    thread,
+};
+
+use crate::backend::{
+    argmax, argmin, count,
+    flat::{self, Strided},
+    gemm, max, mean, min, prod, sum, Backend as BackendTrait,
+};
+use crate::num::{One, Zero};
+
+type Flat = flat::Backend;
+
+/// Runs the compute kernels on `THREADS` scoped worker threads, reusing the flat backend's
+/// strided layout for storage and data movement.
+///
+/// `THREADS == 0` uses [`thread::available_parallelism`]. Kernels whose work is at most
+/// `THRESHOLD` elements run on the calling thread, as do the blocks of a full reduction.
+///
+/// Work is split into contiguous chunks and the partial results are combined in a fixed order
+/// that depends only on `THRESHOLD`, so reductions return the same value whatever the thread
+/// count, including for floats.
+#[derive(Clone, Debug)]
+pub struct Backend<const THREADS: usize = 0, const THRESHOLD: usize = 4096> {}
+
+impl<const THREADS: usize, const THRESHOLD: usize> Backend<THREADS, THRESHOLD> {
+    fn threads(work: usize) -> usize {
+        if work <= THRESHOLD {
+            1
+        } else if THREADS == 0 {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            THREADS
+        }
+    }
+
+    /// Size of the blocks a full reduction is split into before the partials are combined.
+    fn block() -> usize {
+        THRESHOLD.max(1)
+    }
+
+    /// Evaluates `f` at every index in `0..len`, where each call costs about `cost` elements of
+    /// work, splitting the range into contiguous chunks across the workers.
+    fn generate<U: Send>(len: usize, cost: usize, f: impl Fn(usize) -> U + Sync) -> Vec<U> {
+        let threads = Self::threads(len.saturating_mul(cost)).min(len);
+        if threads <= 1 {
+            return (0..len).map(f).collect();
+        }
+        let chunk = len.div_ceil(threads);
+        let f = &f;
+        thread::scope(|s| {
+            let workers: Vec<_> = (0..len)
+                .step_by(chunk)
+                .map(|start| s.spawn(move || (start..len.min(start + chunk)).map(f).collect()))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| -> Vec<U> { worker.join().unwrap() })
+                .collect()
+        })
+    }
+
+    /// Applies `f` to consecutive blocks of `a`, returning the partial results in order.
+    fn blocks<T: Sync, U: Send>(a: &[T], f: impl Fn(&[T]) -> U + Sync) -> Vec<U> {
+        let block = Self::block();
+        Self::generate(a.len().div_ceil(block), block, |i| {
+            f(&a[i * block..a.len().min((i + 1) * block)])
+        })
+    }
+
+    fn contiguous<T: Clone + Send + Sync, const N: usize>(a: Strided<T, N>) -> Strided<T, N> {
+        if a.is_contiguous() {
+            return a;
+        }
+        let shape = a.shape();
+        let data = Self::generate(shape.iter().product(), 1, |l| {
+            a.data[a.offset(unravel(l, shape))].clone()
+        });
+        Strided::new(data, shape)
+    }
+
+    fn map<T: Clone + Send + Sync, const N: usize>(
+        a: Strided<T, N>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Strided<T, N> {
+        let a = Self::contiguous(a);
+        let data = Self::generate(a.data.len(), 1, |l| f(a.data[l].clone()));
+        Strided::new(data, a.shape())
+    }
+
+    fn zip<T: Clone + Send + Sync, const N: usize>(
+        a: Strided<T, N>,
+        b: Strided<T, N>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Strided<T, N> {
+        assert_eq!(a.shape(), b.shape());
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let data = Self::generate(a.data.len(), 1, |l| f(a.data[l].clone(), b.data[l].clone()));
+        Strided::new(data, a.shape())
+    }
+
+    /// Combines every element of `a` with the element of `b` whose index is `a`'s index
+    /// projected onto `axes`.
+    fn broadcast<T: Clone + Send + Sync, const N: usize, const M: usize>(
+        a: Strided<T, N>,
+        b: &Strided<T, M>,
+        axes: [usize; M],
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Strided<T, N> {
+        for (j, &axis) in axes.iter().enumerate() {
+            assert_eq!(a.shape()[axis], b.shape()[j]);
+        }
+        let a = Self::contiguous(a);
+        let shape = a.shape();
+        let data = Self::generate(a.data.len(), 1, |l| {
+            let index = unravel(l, shape);
+            let b = &b.data[b.offset(axes.map(|axis| index[axis]))];
+            f(a.data[l].clone(), b.clone())
+        });
+        Strided::new(data, shape)
+    }
+
+    /// Applies `f` to every lane along `axis`, keeping the remaining axes in order. Each lane
+    /// is reduced on a single worker.
+    fn reduce<T: Clone + Send + Sync, U: Send, const N: usize, const M: usize>(
+        a: Strided<T, N>,
+        axis: usize,
+        f: impl Fn(&[T]) -> U + Sync,
+    ) -> Strided<U, M> {
+        assert!(axis < N && M + 1 == N, "improper axis");
+        let mut p = [axis; N];
+        let mut shape = [0; M];
+        for (i, other) in (0..N).filter(|&other| other != axis).enumerate() {
+            p[i] = other;
+            shape[i] = a.shape()[other];
+        }
+        let n = a.shape()[axis];
+        let a = Self::contiguous(a.permute(p));
+        let data = Self::generate(
+            shape.iter().product(),
+            n,
+            |i| f(&a.data[i * n..(i + 1) * n]),
+        );
+        Strided::new(data, shape)
+    }
+
+    /// Multiplies the `(m, k)` rows of `a` by the `(k, n)` matrix `b`, one output row per task.
+    fn matmul<T>(a: &[T], b: &[T], (m, k, n): (usize, usize, usize)) -> Vec<T>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy + Send + Sync,
+    {
+        Self::generate(m, k * n, |i| {
+            gemm::naive(&a[i * k..(i + 1) * k], b, (1, k, n))
+        })
+        .concat()
+    }
+}
+
+/// Converts a row-major linear index into an index of `shape`.
+fn unravel<const N: usize>(mut l: usize, shape: [usize; N]) -> [usize; N] {
+    let mut index = [0; N];
+    for (i, d) in index.iter_mut().zip(shape).rev() {
+        *i = l % d;
+        l /= d;
+    }
+    index
+}
+
+fn dot<T: Add<Output = T> + Mul<Output = T> + Zero + Clone>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone())
+}
+
+impl<T: Clone + Send + Sync, const THREADS: usize, const THRESHOLD: usize> BackendTrait<T>
+    for Backend<THREADS, THRESHOLD>
+{
+    type Index = usize;
+    type Dimension = usize;
+    type T0Repr = T;
+    type T1Repr<const D0: usize> = Strided<T, 1>;
+    type T2Repr<const D0: usize, const D1: usize> = Strided<T, 2>;
+    type T3Repr<const D0: usize, const D1: usize, const D2: usize> = Strided<T, 3>;
+
+    fn t0_from_value(v: T) -> Self::T0Repr {
+        v
+    }
+
+    fn t1_from_array<const D0: usize>(a: [T; D0]) -> Self::T1Repr<D0> {
+        Flat::t1_from_array::<D0>(a)
+    }
+
+    fn t2_from_array<const D0: usize, const D1: usize>(a: [[T; D1]; D0]) -> Self::T2Repr<D0, D1> {
+        Flat::t2_from_array::<D0, D1>(a)
+    }
+
+    fn t3_from_array<const D0: usize, const D1: usize, const D2: usize>(
+        a: [[[T; D2]; D1]; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_from_array::<D0, D1, D2>(a)
+    }
+
+    fn t1_from_vec<const D0: usize>(v: Vec<T>) -> Self::T1Repr<D0> {
+        Flat::t1_from_vec::<D0>(v)
+    }
+
+    fn t2_from_vec<const D0: usize, const D1: usize>(v: Vec<T>) -> Self::T2Repr<D0, D1> {
+        Flat::t2_from_vec::<D0, D1>(v)
+    }
+
+    fn t3_from_vec<const D0: usize, const D1: usize, const D2: usize>(
+        v: Vec<T>,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_from_vec::<D0, D1, D2>(v)
+    }
+
+    fn t0_into_value(a: Self::T0Repr) -> T {
+        Flat::t0_into_value(a)
+    }
+
+    fn t1_into_vec<const D0: usize>(a: Self::T1Repr<D0>) -> Vec<T> {
+        Self::contiguous(a).data
+    }
+
+    fn t2_into_vec<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Vec<T> {
+        Self::contiguous(a).data
+    }
+
+    fn t3_into_vec<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Vec<T> {
+        Self::contiguous(a).data
+    }
+
+    fn t1_reshape1<const D0: usize, const RD0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape2<const D0: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t1_reshape3<const D0: usize, const RD0: usize, const RD1: usize, const RD2: usize>(
+        a: Self::T1Repr<D0>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t1_into_vec::<D0>(a))
+    }
+
+    fn t2_reshape1<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape2<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t2_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t2_into_vec::<D0, D1>(a))
+    }
+
+    fn t3_reshape1<const D0: usize, const D1: usize, const D2: usize, const RD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T1Repr<RD0> {
+        Self::t1_from_vec::<RD0>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape2<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Self::t2_from_vec::<RD0, RD1>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t3_reshape3<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Self::t3_from_vec::<RD0, RD1, RD2>(Self::t3_into_vec::<D0, D1, D2>(a))
+    }
+
+    fn t0_get(a: &Self::T0Repr) -> &T {
+        Flat::t0_get(a)
+    }
+
+    fn t0_get_mut(a: &mut Self::T0Repr) -> &mut T {
+        Flat::t0_get_mut(a)
+    }
+
+    fn t1_get<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        Flat::t1_get::<D0>(a, i)
+    }
+
+    fn t1_get_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        Flat::t1_get_mut::<D0>(a, i)
+    }
+
+    fn t2_get<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        Flat::t2_get::<D0, D1>(a, i, j)
+    }
+
+    fn t2_get_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        Flat::t2_get_mut::<D0, D1>(a, i, j)
+    }
+
+    fn t3_get<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        Flat::t3_get::<D0, D1, D2>(a, i, j, k)
+    }
+
+    fn t3_get_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        Flat::t3_get_mut::<D0, D1, D2>(a, i, j, k)
+    }
+
+    unsafe fn t1_get_unchecked<const D0: usize>(a: &Self::T1Repr<D0>, i: usize) -> &T {
+        Flat::t1_get_unchecked::<D0>(a, i)
+    }
+
+    unsafe fn t1_get_unchecked_mut<const D0: usize>(a: &mut Self::T1Repr<D0>, i: usize) -> &mut T {
+        Flat::t1_get_unchecked_mut::<D0>(a, i)
+    }
+
+    unsafe fn t2_get_unchecked<const D0: usize, const D1: usize>(
+        a: &Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &T {
+        Flat::t2_get_unchecked::<D0, D1>(a, i, j)
+    }
+
+    unsafe fn t2_get_unchecked_mut<const D0: usize, const D1: usize>(
+        a: &mut Self::T2Repr<D0, D1>,
+        i: usize,
+        j: usize,
+    ) -> &mut T {
+        Flat::t2_get_unchecked_mut::<D0, D1>(a, i, j)
+    }
+
+    unsafe fn t3_get_unchecked<const D0: usize, const D1: usize, const D2: usize>(
+        a: &Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &T {
+        Flat::t3_get_unchecked::<D0, D1, D2>(a, i, j, k)
+    }
+
+    unsafe fn t3_get_unchecked_mut<const D0: usize, const D1: usize, const D2: usize>(
+        a: &mut Self::T3Repr<D0, D1, D2>,
+        i: usize,
+        j: usize,
+        k: usize,
+    ) -> &mut T {
+        Flat::t3_get_unchecked_mut::<D0, D1, D2>(a, i, j, k)
+    }
+
+    fn t0_zero() -> Self::T0Repr
+    where
+        T: Zero,
+    {
+        Flat::t0_zero()
+    }
+
+    fn t0_one() -> Self::T0Repr
+    where
+        T: One,
+    {
+        Flat::t0_one()
+    }
+
+    fn t1_zeros<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t1_zeros::<D0>()
+    }
+
+    fn t1_ones<const D0: usize>() -> Self::T1Repr<D0>
+    where
+        T: One + Copy,
+    {
+        Flat::t1_ones::<D0>()
+    }
+
+    fn t2_zeros<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_zeros::<D0, D1>()
+    }
+
+    fn t2_ones<const D0: usize, const D1: usize>() -> Self::T2Repr<D0, D1>
+    where
+        T: One + Copy,
+    {
+        Flat::t2_ones::<D0, D1>()
+    }
+
+    fn t3_ones<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: One + Copy,
+    {
+        Flat::t3_ones::<D0, D1, D2>()
+    }
+
+    fn t3_zeros<const D0: usize, const D1: usize, const D2: usize>() -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t3_zeros::<D0, D1, D2>()
+    }
+
+    fn t2_identity<const D0: usize>() -> Self::T2Repr<D0, D0>
+    where
+        T: Zero + One + Copy,
+    {
+        Flat::t2_identity::<D0>()
+    }
+
+    fn t2_transpose<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D1, D0>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_transpose::<D0, D1>(a)
+    }
+
+    fn t2_permute<const D0: usize, const D1: usize, const RD0: usize, const RD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        p: (usize, usize),
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t2_permute::<D0, D1, RD0, RD1>(a, p)
+    }
+
+    fn t3_permute<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        p: (usize, usize, usize),
+    ) -> Self::T3Repr<RD0, RD1, RD2>
+    where
+        T: Zero + Copy,
+    {
+        Flat::t3_permute::<D0, D1, D2, RD0, RD1, RD2>(a, p)
+    }
+
+    fn t1_concat<const D0: usize, const OD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<OD0>,
+    ) -> Self::T1Repr<RD0> {
+        Flat::t1_concat::<D0, OD0, RD0>(a, b)
+    }
+
+    fn t2_concat<
+        const D0: usize,
+        const D1: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<OD0, OD1>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1> {
+        Flat::t2_concat::<D0, D1, OD0, OD1, RD0, RD1>(a, b, axis)
+    }
+
+    fn t3_concat<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const OD0: usize,
+        const OD1: usize,
+        const OD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<OD0, OD1, OD2>,
+        axis: usize,
+    ) -> Self::T3Repr<RD0, RD1, RD2> {
+        Flat::t3_concat::<D0, D1, D2, OD0, OD1, OD2, RD0, RD1, RD2>(a, b, axis)
+    }
+
+    fn t2_stack<const D0: usize, const D1: usize>(
+        a: [Self::T1Repr<D1>; D0],
+    ) -> Self::T2Repr<D0, D1> {
+        Flat::t2_stack::<D0, D1>(a)
+    }
+
+    fn t3_stack<const D0: usize, const D1: usize, const D2: usize>(
+        a: [Self::T2Repr<D1, D2>; D0],
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Flat::t3_stack::<D0, D1, D2>(a)
+    }
+
+    fn t1_split<const D0: usize, const LD0: usize, const RD0: usize>(
+        a: Self::T1Repr<D0>,
+        at: usize,
+    ) -> (Self::T1Repr<LD0>, Self::T1Repr<RD0>) {
+        Flat::t1_split::<D0, LD0, RD0>(a, at)
+    }
+
+    fn t2_split<
+        const D0: usize,
+        const D1: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T2Repr<D0, D1>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T2Repr<LD0, LD1>, Self::T2Repr<RD0, RD1>) {
+        Flat::t2_split::<D0, D1, LD0, LD1, RD0, RD1>(a, at, axis)
+    }
+
+    fn t3_split<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const LD0: usize,
+        const LD1: usize,
+        const LD2: usize,
+        const RD0: usize,
+        const RD1: usize,
+        const RD2: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        at: usize,
+        axis: usize,
+    ) -> (Self::T3Repr<LD0, LD1, LD2>, Self::T3Repr<RD0, RD1, RD2>) {
+        Flat::t3_split::<D0, D1, D2, LD0, LD1, LD2, RD0, RD1, RD2>(a, at, axis)
+    }
+
+    fn t0_t0_add(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Add<Output = T>,
+    {
+        a + b
+    }
+
+    fn t1_t0_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::map(a, |a| a + b)
+    }
+
+    fn t1_t1_add<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a + b)
+    }
+
+    fn t2_t0_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::map(a, |a| a + b)
+    }
+
+    fn t2_t1_add<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a + b)
+    }
+
+    fn t2_t2_add<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Add<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a + b)
+    }
+
+    fn t3_t0_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::map(a, |a| a + b)
+    }
+
+    fn t3_t1_add<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a + b)
+    }
+
+    fn t3_t2_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [1, 2], |a, b| a + b)
+    }
+
+    fn t3_t3_add<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Add<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a + b)
+    }
+
+    fn t0_t0_sub(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Sub<Output = T>,
+    {
+        a - b
+    }
+
+    fn t1_t0_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::map(a, |a| a - b)
+    }
+
+    fn t1_t1_sub<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Sub<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a - b)
+    }
+
+    fn t2_t0_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::map(a, |a| a - b)
+    }
+
+    fn t2_t1_sub<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a - b)
+    }
+
+    fn t2_t2_sub<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Sub<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a - b)
+    }
+
+    fn t3_t0_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::map(a, |a| a - b)
+    }
+
+    fn t3_t1_sub<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a - b)
+    }
+
+    fn t3_t2_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [1, 2], |a, b| a - b)
+    }
+
+    fn t3_t3_sub<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Sub<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a - b)
+    }
+
+    fn t0_t0_mul(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Mul<Output = T>,
+    {
+        a * b
+    }
+
+    fn t1_t0_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::map(a, |a| a * b)
+    }
+
+    fn t1_t1_mul<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Mul<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a * b)
+    }
+
+    fn t2_t0_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::map(a, |a| a * b)
+    }
+
+    fn t2_t1_mul<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a * b)
+    }
+
+    fn t2_t2_mul<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Mul<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a * b)
+    }
+
+    fn t3_t0_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::map(a, |a| a * b)
+    }
+
+    fn t3_t1_mul<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a * b)
+    }
+
+    fn t3_t2_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [1, 2], |a, b| a * b)
+    }
+
+    fn t3_t3_mul<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Mul<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a * b)
+    }
+
+    fn t0_t0_div(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Div<Output = T>,
+    {
+        a / b
+    }
+
+    fn t1_t0_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::map(a, |a| a / b)
+    }
+
+    fn t1_t1_div<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Div<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a / b)
+    }
+
+    fn t2_t0_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::map(a, |a| a / b)
+    }
+
+    fn t2_t1_div<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a / b)
+    }
+
+    fn t2_t2_div<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Div<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a / b)
+    }
+
+    fn t3_t0_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::map(a, |a| a / b)
+    }
+
+    fn t3_t1_div<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a / b)
+    }
+
+    fn t3_t2_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [1, 2], |a, b| a / b)
+    }
+
+    fn t3_t3_div<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Div<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a / b)
+    }
+
+    fn t0_t0_rem(a: Self::T0Repr, b: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Rem<Output = T>,
+    {
+        a % b
+    }
+
+    fn t1_t0_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T0Repr) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::map(a, |a| a % b)
+    }
+
+    fn t1_t1_rem<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Rem<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a % b)
+    }
+
+    fn t2_t0_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T0Repr,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::map(a, |a| a % b)
+    }
+
+    fn t2_t1_rem<const D0: usize, const D1: usize, const OD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a % b)
+    }
+
+    fn t2_t2_rem<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T2Repr<D0, D1>
+    where
+        T: Rem<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a % b)
+    }
+
+    fn t3_t0_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T0Repr,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::map(a, |a| a % b)
+    }
+
+    fn t3_t1_rem<const D0: usize, const D1: usize, const D2: usize, const OD0: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T1Repr<OD0>,
+        along: usize,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [along], |a, b| a % b)
+    }
+
+    fn t3_t2_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self::broadcast(a, &b, [1, 2], |a, b| a % b)
+    }
+
+    fn t3_t3_rem<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Rem<Output = T>,
+    {
+        Self::zip(a, b, |a, b| a % b)
+    }
+
+    fn t0_neg(a: Self::T0Repr) -> Self::T0Repr
+    where
+        T: Neg<Output = T>,
+    {
+        -a
+    }
+
+    fn t1_neg<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T1Repr<D0>
+    where
+        T: Neg<Output = T>,
+    {
+        Self::map(a, |a| -a)
+    }
+
+    fn t2_neg<const D0: usize, const D1: usize>(a: Self::T2Repr<D0, D1>) -> Self::T2Repr<D0, D1>
+    where
+        T: Neg<Output = T>,
+    {
+        Self::map(a, |a| -a)
+    }
+
+    fn t3_neg<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+    ) -> Self::T3Repr<D0, D1, D2>
+    where
+        T: Neg<Output = T>,
+    {
+        Self::map(a, |a| -a)
+    }
+
+    fn t0_map(a: Self::T0Repr, f: impl Fn(T) -> T + Sync) -> Self::T0Repr {
+        f(a)
+    }
+
+    fn t1_map<const D0: usize>(a: Self::T1Repr<D0>, f: impl Fn(T) -> T + Sync) -> Self::T1Repr<D0> {
+        Self::map(a, f)
+    }
+
+    fn t2_map<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        Self::map(a, f)
+    }
+
+    fn t3_map<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Self::map(a, f)
+    }
+
+    fn t0_zip_with(a: Self::T0Repr, b: Self::T0Repr, f: impl Fn(T, T) -> T + Sync) -> Self::T0Repr {
+        f(a, b)
+    }
+
+    fn t1_zip_with<const D0: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T1Repr<D0>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T1Repr<D0> {
+        Self::zip(a, b, f)
+    }
+
+    fn t2_zip_with<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D0, D1>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T2Repr<D0, D1> {
+        Self::zip(a, b, f)
+    }
+
+    fn t3_zip_with<const D0: usize, const D1: usize, const D2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D1, D2>,
+        f: impl Fn(T, T) -> T + Sync,
+    ) -> Self::T3Repr<D0, D1, D2> {
+        Self::zip(a, b, f)
+    }
+
+    fn t0_fold<U>(a: Self::T0Repr, init: U, mut f: impl FnMut(U, T) -> U) -> U {
+        f(init, a)
+    }
+
+    fn t1_fold<const D0: usize, U>(a: Self::T1Repr<D0>, init: U, f: impl FnMut(U, T) -> U) -> U {
+        Flat::t1_fold::<D0, U>(a, init, f)
+    }
+
+    fn t2_fold<const D0: usize, const D1: usize, U>(
+        a: Self::T2Repr<D0, D1>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        Flat::t2_fold::<D0, D1, U>(a, init, f)
+    }
+
+    fn t3_fold<const D0: usize, const D1: usize, const D2: usize, U>(
+        a: Self::T3Repr<D0, D1, D2>,
+        init: U,
+        f: impl FnMut(U, T) -> U,
+    ) -> U {
+        Flat::t3_fold::<D0, D1, D2, U>(a, init, f)
+    }
+
+    fn t1_sum<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        let a = Self::contiguous(a);
+        sum(&Self::blocks(&a.data, sum))
+    }
+
+    fn t1_prod<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        let a = Self::contiguous(a);
+        prod(&Self::blocks(&a.data, prod))
+    }
+
+    fn t1_min<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        let a = Self::contiguous(a);
+        min(&Self::blocks(&a.data, min))
+    }
+
+    fn t1_max<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: PartialOrd + Copy,
+    {
+        let a = Self::contiguous(a);
+        max(&Self::blocks(&a.data, max))
+    }
+
+    fn t1_mean<const D0: usize>(a: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        let n = count(a.shape()[0]);
+        Self::t1_sum::<D0>(a) / n
+    }
+
+    fn t1_argmin<const D0: usize>(a: Self::T1Repr<D0>) -> <Self as BackendTrait<usize>>::T0Repr
+    where
+        T: PartialOrd,
+    {
+        let a = Self::contiguous(a);
+        let block = Self::block();
+        let candidates: Vec<usize> = Self::blocks(&a.data, argmin)
+            .into_iter()
+            .enumerate()
+            .map(|(i, j)| i * block + j)
+            .collect();
+        let values: Vec<T> = candidates.iter().map(|&i| a.data[i].clone()).collect();
+        candidates[argmin(&values)]
+    }
+
+    fn t1_argmax<const D0: usize>(a: Self::T1Repr<D0>) -> <Self as BackendTrait<usize>>::T0Repr
+    where
+        T: PartialOrd,
+    {
+        let a = Self::contiguous(a);
+        let block = Self::block();
+        let candidates: Vec<usize> = Self::blocks(&a.data, argmax)
+            .into_iter()
+            .enumerate()
+            .map(|(i, j)| i * block + j)
+            .collect();
+        let values: Vec<T> = candidates.iter().map(|&i| a.data[i].clone()).collect();
+        candidates[argmax(&values)]
+    }
+
+    fn t2_sum_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, sum)
+    }
+
+    fn t2_prod_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, prod)
+    }
+
+    fn t2_min_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, min)
+    }
+
+    fn t2_max_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, max)
+    }
+
+    fn t2_mean_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> Self::T1Repr<RD0>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, mean)
+    }
+
+    fn t2_argmin_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> <Self as BackendTrait<usize>>::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, argmin)
+    }
+
+    fn t2_argmax_axis<const D0: usize, const D1: usize, const RD0: usize>(
+        a: Self::T2Repr<D0, D1>,
+        axis: usize,
+    ) -> <Self as BackendTrait<usize>>::T1Repr<RD0>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 2, 1>(a, axis, argmax)
+    }
+
+    fn t3_sum_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, sum)
+    }
+
+    fn t3_prod_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, prod)
+    }
+
+    fn t3_min_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, min)
+    }
+
+    fn t3_max_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, max)
+    }
+
+    fn t3_mean_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> Self::T2Repr<RD0, RD1>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, mean)
+    }
+
+    fn t3_argmin_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> <Self as BackendTrait<usize>>::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, argmin)
+    }
+
+    fn t3_argmax_axis<
+        const D0: usize,
+        const D1: usize,
+        const D2: usize,
+        const RD0: usize,
+        const RD1: usize,
+    >(
+        a: Self::T3Repr<D0, D1, D2>,
+        axis: usize,
+    ) -> <Self as BackendTrait<usize>>::T2Repr<RD0, RD1>
+    where
+        T: PartialOrd + Copy,
+    {
+        Self::reduce::<_, _, 3, 2>(a, axis, argmax)
+    }
+
+    fn t1_t1_dot<const D0: usize>(a: Self::T1Repr<D0>, b: Self::T1Repr<D0>) -> Self::T0Repr
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero,
+    {
+        assert_eq!(a.shape(), b.shape());
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let block = Self::block();
+        Self::generate(a.data.len().div_ceil(block), block, |i| {
+            let range = i * block..((i + 1) * block).min(a.data.len());
+            dot(&a.data[range.clone()], &b.data[range])
+        })
+        .into_iter()
+        .fold(T::zero(), |acc, a| acc + a)
+    }
+
+    fn t2_t1_matvec<const D0: usize, const D1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T1Repr<D1>,
+    ) -> Self::T1Repr<D0>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let [m, k] = a.shape();
+        assert_eq!(k, b.shape()[0]);
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let data = Self::generate(m, k, |i| dot(&a.data[i * k..(i + 1) * k], &b.data));
+        Strided::new(data, [m])
+    }
+
+    fn t1_t2_vecmat<const D0: usize, const D1: usize>(
+        a: Self::T1Repr<D0>,
+        b: Self::T2Repr<D0, D1>,
+    ) -> Self::T1Repr<D1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let [k, n] = b.shape();
+        assert_eq!(a.shape()[0], k);
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let data = Self::generate(n, k, |j| {
+            (0..k).fold(T::zero(), |acc, p| acc + a.data[p] * b.data[p * n + j])
+        });
+        Strided::new(data, [n])
+    }
+
+    fn t2_t2_matmul<const D0: usize, const D1: usize, const OD1: usize>(
+        a: Self::T2Repr<D0, D1>,
+        b: Self::T2Repr<D1, OD1>,
+    ) -> Self::T2Repr<D0, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let ([m, k], [bk, n]) = (a.shape(), b.shape());
+        assert_eq!(k, bk);
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        Strided::new(Self::matmul(&a.data, &b.data, (m, k, n)), [m, n])
+    }
+
+    fn t3_t2_matmul<const D0: usize, const D1: usize, const D2: usize, const OD1: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T2Repr<D2, OD1>,
+    ) -> Self::T3Repr<D0, D1, OD1>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let ([d0, d1, k], [bk, n]) = (a.shape(), b.shape());
+        assert_eq!(k, bk);
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let data = Self::matmul(&a.data, &b.data, (d0 * d1, k, n));
+        Strided::new(data, [d0, d1, n])
+    }
+
+    fn t3_t3_bmm<const D0: usize, const D1: usize, const D2: usize, const OD2: usize>(
+        a: Self::T3Repr<D0, D1, D2>,
+        b: Self::T3Repr<D0, D2, OD2>,
+    ) -> Self::T3Repr<D0, D1, OD2>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        let ([d0, m, k], [bd0, bk, n]) = (a.shape(), b.shape());
+        assert_eq!((d0, k), (bd0, bk));
+        let (a, b) = (Self::contiguous(a), Self::contiguous(b));
+        let data = Self::generate(d0 * m, k * n, |r| {
+            let b = &b.data[r / m * k * n..(r / m + 1) * k * n];
+            gemm::naive(&a.data[r * k..(r + 1) * k], b, (1, k, n))
+        })
+        .concat();
+        Strided::new(data, [d0, m, n])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three workers and no sequential cutoff, so every kernel takes the parallel path.
+    type Backend = super::Backend<3, 0>;
+
+    fn s1<T>(a: Vec<T>) -> Strided<T, 1> {
+        let shape = [a.len()];
+        Strided::new(a, shape)
+    }
+
+    fn s2<T>(a: Vec<Vec<T>>) -> Strided<T, 2> {
+        let shape = [a.len(), a[0].len()];
+        Strided::new(a.into_iter().flatten().collect(), shape)
+    }
+
+    fn s3<T>(a: Vec<Vec<Vec<T>>>) -> Strided<T, 3> {
+        let shape = [a.len(), a[0].len(), a[0][0].len()];
+        Strided::new(a.into_iter().flatten().flatten().collect(), shape)
+    }
+
+    #[test]
+    fn test_t0_from_value() {
+        let value: u8 = Backend::t0_from_value(7);
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_t1_from_array() {
+        let a: Strided<u8, 1> = Backend::t1_from_array([1, 2, 3]);
+        assert_eq!(a, s1(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_t2_from_array() {
+        let a: Strided<u8, 2> = Backend::t2_from_array([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(a, s2(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_t3_from_array() {
+        let a: Strided<u8, 3> = Backend::t3_from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert_eq!(
+            a,
+            s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t2_from_vec() {
+        let a: Strided<u8, 2> = Backend::t2_from_vec::<3, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a, s2(vec![vec![1, 2], vec![3, 4], vec![5, 6]]));
+    }
+
+    #[test]
+    fn test_t3_from_vec() {
+        let a: Strided<u8, 3> = Backend::t3_from_vec::<3, 1, 2>(vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            a,
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]])
+        );
+    }
+
+    #[test]
+    fn test_t2_into_vec() {
+        assert_eq!(
+            Backend::t2_into_vec::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_t3_into_vec() {
+        assert_eq!(
+            Backend::t3_into_vec::<2, 2, 2>(s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 6], vec![7, 8]]
+            ])),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_t0_get() {
+        let mut a: u8 = 3;
+        assert_eq!(*Backend::t0_get(&a), 3);
+        *Backend::t0_get_mut(&mut a) = 4;
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn test_t1_get() {
+        let mut a = s1(vec![1, 2, 3]);
+        assert_eq!(*Backend::t1_get::<3>(&a, 1), 2);
+        *Backend::t1_get_mut::<3>(&mut a, 1) = 5;
+        assert_eq!(a, s1(vec![1, 5, 3]));
+    }
+
+    #[test]
+    fn test_t2_get() {
+        let mut a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(*Backend::t2_get::<2, 3>(&a, 1, 2), 6);
+        *Backend::t2_get_mut::<2, 3>(&mut a, 1, 2) = 7;
+        assert_eq!(a, s2(vec![vec![1, 2, 3], vec![4, 5, 7]]));
+    }
+
+    #[test]
+    fn test_t3_get() {
+        let mut a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(*Backend::t3_get::<2, 2, 2>(&a, 1, 0, 1), 6);
+        *Backend::t3_get_mut::<2, 2, 2>(&mut a, 1, 0, 1) = 9;
+        assert_eq!(
+            a,
+            s3(vec![
+                vec![vec![1, 2], vec![3, 4]],
+                vec![vec![5, 9], vec![7, 8]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        let b = s2(vec![vec![5, 6], vec![7, 8]]);
+        let c = s1(vec![5, 6]);
+        unsafe {
+            assert_eq!(*Backend::t3_get_unchecked::<2, 2, 2>(&a, 0, 1, 0), 3);
+            *Backend::t3_get_unchecked_mut::<2, 2, 2>(&mut a, 0, 1, 0) = 0;
+            assert_eq!(*Backend::t2_get_unchecked::<2, 2>(&b, 1, 1), 8);
+            assert_eq!(*Backend::t1_get_unchecked::<2>(&c, 0), 5);
+        }
+        assert_eq!(*Backend::t3_get::<2, 2, 2>(&a, 0, 1, 0), 0);
+    }
+
+    #[test]
+    fn test_t0_zero() {
+        let zero: u8 = Backend::t0_zero();
+        assert_eq!(zero, 0);
+    }
+
+    #[test]
+    fn test_t0_one() {
+        let one: u8 = Backend::t0_one();
+        assert_eq!(one, 1);
+    }
+
+    #[test]
+    fn test_t1_zeros() {
+        let zeros: Strided<u8, 1> = Backend::t1_zeros::<3>();
+        assert_eq!(zeros, s1(vec![0, 0, 0]));
+    }
+
+    #[test]
+    fn test_t1_ones() {
+        let ones: Strided<u8, 1> = Backend::t1_ones::<3>();
+        assert_eq!(ones, s1(vec![1, 1, 1]));
+    }
+
+    #[test]
+    fn test_t2_zeros() {
+        let zeros: Strided<u8, 2> = Backend::t2_zeros::<2, 3>();
+        assert_eq!(zeros, s2(vec![vec![0, 0, 0], vec![0, 0, 0]]));
+    }
+
+    #[test]
+    fn test_t2_ones() {
+        let ones: Strided<u8, 2> = Backend::t2_ones::<2, 3>();
+        assert_eq!(ones, s2(vec![vec![1, 1, 1], vec![1, 1, 1]]));
+    }
+
+    #[test]
+    fn test_t3_zeros() {
+        let zeros: Strided<u8, 3> = Backend::t3_zeros::<2, 3, 4>();
+        assert_eq!(
+            zeros,
+            s3(vec![
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]],
+                vec![vec![0, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 0, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_ones() {
+        let ones: Strided<u8, 3> = Backend::t3_ones::<2, 3, 4>();
+        assert_eq!(
+            ones,
+            s3(vec![
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]],
+                vec![vec![1, 1, 1, 1], vec![1, 1, 1, 1], vec![1, 1, 1, 1]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t2_identity() {
+        let eye: Strided<u8, 2> = Backend::t2_identity::<3>();
+        assert_eq!(eye, s2(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]));
+    }
+
+    #[test]
+    fn test_t2_transpose() {
+        assert_eq!(
+            Backend::t2_transpose::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            s2(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t2_permute() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(Backend::t2_permute::<2, 3, 2, 3>(a.clone(), (0, 1)), a);
+        assert_eq!(
+            Backend::t2_permute::<2, 3, 3, 2>(a, (1, 0)),
+            s2(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_permute() {
+        let input = s3(vec![
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+            vec![vec![10, 11, 12], vec![13, 14, 15], vec![16, 17, 18]],
+        ]);
+        let permuted = Backend::t3_permute::<2, 3, 3, 3, 2, 3>(input, (2, 0, 1));
+        let expected = s3(vec![
+            vec![vec![1, 4, 7], vec![10, 13, 16]],
+            vec![vec![2, 5, 8], vec![11, 14, 17]],
+            vec![vec![3, 6, 9], vec![12, 15, 18]],
+        ]);
+        assert_eq!(permuted, expected);
+    }
+
+    #[test]
+    fn test_t1_concat() {
+        assert_eq!(
+            Backend::t1_concat::<2, 3, 5>(s1(vec![1, 2]), s1(vec![3, 4, 5])),
+            s1(vec![1, 2, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_t2_concat() {
+        let a = s2(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 1, 2, 3, 2>(a.clone(), s2(vec![vec![5, 6]]), 0),
+            s2(vec![vec![1, 2], vec![3, 4], vec![5, 6]])
+        );
+        assert_eq!(
+            Backend::t2_concat::<2, 2, 2, 1, 2, 3>(a, s2(vec![vec![5], vec![6]]), 1),
+            s2(vec![vec![1, 2, 5], vec![3, 4, 6]])
+        );
+    }
+
+    #[test]
+    fn test_t3_concat() {
+        let a = s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]]);
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 1, 1, 2, 3, 1, 2>(
+                a.clone(),
+                s3(vec![vec![vec![5, 6]]]),
+                0
+            ),
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]], vec![vec![5, 6]]])
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 2, 2, 2, 2>(
+                a.clone(),
+                s3(vec![vec![vec![5, 6]], vec![vec![7, 8]]]),
+                1
+            ),
+            s3(vec![
+                vec![vec![1, 2], vec![5, 6]],
+                vec![vec![3, 4], vec![7, 8]]
+            ])
+        );
+        assert_eq!(
+            Backend::t3_concat::<2, 1, 2, 2, 1, 1, 2, 1, 3>(
+                a,
+                s3(vec![vec![vec![5]], vec![vec![6]]]),
+                2
+            ),
+            s3(vec![vec![vec![1, 2, 5]], vec![vec![3, 4, 6]]])
+        );
+    }
+
+    #[test]
+    fn test_t2_stack() {
+        assert_eq!(
+            Backend::t2_stack::<2, 2>([s1(vec![1, 2]), s1(vec![3, 4])]),
+            s2(vec![vec![1, 2], vec![3, 4]])
+        );
+    }
+
+    #[test]
+    fn test_t3_stack() {
+        assert_eq!(
+            Backend::t3_stack::<2, 1, 2>([s2(vec![vec![1, 2]]), s2(vec![vec![3, 4]])]),
+            s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]])
+        );
+    }
+
+    #[test]
+    fn test_t1_split() {
+        assert_eq!(
+            Backend::t1_split::<5, 2, 3>(s1(vec![1, 2, 3, 4, 5]), 2),
+            (s1(vec![1, 2]), s1(vec![3, 4, 5]))
+        );
+    }
+
+    #[test]
+    fn test_t2_split() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_split::<2, 3, 1, 3, 1, 3>(a.clone(), 1, 0),
+            (s2(vec![vec![1, 2, 3]]), s2(vec![vec![4, 5, 6]]))
+        );
+        assert_eq!(
+            Backend::t2_split::<2, 3, 2, 1, 2, 2>(a, 1, 1),
+            (s2(vec![vec![1], vec![4]]), s2(vec![vec![2, 3], vec![5, 6]]))
+        );
+    }
+
+    #[test]
+    fn test_t3_split() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 1, 2, 2, 1, 2, 2>(a.clone(), 1, 0),
+            (
+                s3(vec![vec![vec![1, 2], vec![3, 4]]]),
+                s3(vec![vec![vec![5, 6], vec![7, 8]]])
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 1, 2, 2, 1, 2>(a.clone(), 1, 1),
+            (
+                s3(vec![vec![vec![1, 2]], vec![vec![5, 6]]]),
+                s3(vec![vec![vec![3, 4]], vec![vec![7, 8]]])
+            )
+        );
+        assert_eq!(
+            Backend::t3_split::<2, 2, 2, 2, 2, 1, 2, 2, 1>(a, 1, 2),
+            (
+                s3(vec![vec![vec![1], vec![3]], vec![vec![5], vec![7]]]),
+                s3(vec![vec![vec![2], vec![4]], vec![vec![6], vec![8]]])
+            )
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_add() {
+        assert_eq!(Backend::t0_t0_add(1, 2), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_add() {
+        assert_eq!(
+            Backend::t1_t0_add::<3>(s1(vec![1, 2, 3]), 2),
+            s1(vec![3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_add() {
+        assert_eq!(
+            Backend::t1_t1_add::<3>(s1(vec![1, 2, 3]), s1(vec![4, 5, 6])),
+            s1(vec![5, 7, 9])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_add() {
+        assert_eq!(
+            Backend::t2_t0_add::<2, 3>(s2(vec![vec![1, 2, 3], vec![4, 5, 6]]), 2),
+            s2(vec![vec![3, 4, 5], vec![6, 7, 8]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_0() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 2>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s2(vec![vec![3, 4, 5], vec![7, 8, 9]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_add_along_1() {
+        assert_eq!(
+            Backend::t2_t1_add::<2, 3, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![3, 5, 7], vec![6, 8, 10]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_add() {
+        assert_eq!(
+            Backend::t2_t2_add::<2, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s2(vec![vec![2, 3, 4], vec![5, 6, 7]])
+            ),
+            s2(vec![vec![3, 5, 7], vec![9, 11, 13]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_sub() {
+        assert_eq!(Backend::t0_t0_sub(9, 3), 6);
+    }
+
+    #[test]
+    fn test_t1_t0_sub() {
+        assert_eq!(
+            Backend::t1_t0_sub::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![4, 6, 7])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_sub() {
+        assert_eq!(
+            Backend::t1_t1_sub::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![2, 5, 7])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_sub() {
+        assert_eq!(
+            Backend::t2_t0_sub::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![4, 6, 7], vec![8, 10, 13]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![4, 6, 7], vec![6, 8, 11]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t2_t1_sub::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![4, 5, 5], vec![8, 9, 11]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_sub() {
+        assert_eq!(
+            Backend::t2_t2_sub::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![2, 5, 7], vec![7, 7, 8]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_mul() {
+        assert_eq!(Backend::t0_t0_mul(9, 3), 27);
+    }
+
+    #[test]
+    fn test_t1_t0_mul() {
+        assert_eq!(
+            Backend::t1_t0_mul::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![12, 16, 18])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_mul() {
+        assert_eq!(
+            Backend::t1_t1_mul::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![24, 24, 18])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_mul() {
+        assert_eq!(
+            Backend::t2_t0_mul::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![12, 16, 18], vec![20, 24, 30]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![12, 16, 18], vec![40, 48, 60]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t2_t1_mul::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![12, 24, 36], vec![20, 36, 60]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_mul() {
+        assert_eq!(
+            Backend::t2_t2_mul::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![24, 24, 18], vec![30, 60, 105]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_div() {
+        assert_eq!(Backend::t0_t0_div(9, 3), 3);
+    }
+
+    #[test]
+    fn test_t1_t0_div() {
+        assert_eq!(
+            Backend::t1_t0_div::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![3, 4, 4])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_div() {
+        assert_eq!(
+            Backend::t1_t1_div::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![1, 2, 4])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_div() {
+        assert_eq!(
+            Backend::t2_t0_div::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![3, 4, 4], vec![5, 6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_0() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![3, 4, 4], vec![2, 3, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_div_along_1() {
+        assert_eq!(
+            Backend::t2_t1_div::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![3, 2, 2], vec![5, 4, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_div() {
+        assert_eq!(
+            Backend::t2_t2_div::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![1, 2, 4], vec![3, 2, 2]])
+        );
+    }
+
+    #[test]
+    fn test_t0_t0_rem() {
+        assert_eq!(Backend::t0_t0_rem(9, 3), 0);
+    }
+
+    #[test]
+    fn test_t1_t0_rem() {
+        assert_eq!(
+            Backend::t1_t0_rem::<3>(s1(vec![6, 8, 9]), 2),
+            s1(vec![0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_rem() {
+        assert_eq!(
+            Backend::t1_t1_rem::<3>(s1(vec![6, 8, 9]), s1(vec![4, 3, 2])),
+            s1(vec![2, 2, 1])
+        );
+    }
+
+    #[test]
+    fn test_t2_t0_rem() {
+        assert_eq!(
+            Backend::t2_t0_rem::<2, 3>(s2(vec![vec![6, 8, 9], vec![10, 12, 15]]), 2),
+            s2(vec![vec![0, 0, 1], vec![0, 0, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 2>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 4]),
+                0
+            ),
+            s2(vec![vec![0, 0, 1], vec![2, 0, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t2_t1_rem::<2, 3, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s1(vec![2, 3, 4]),
+                1
+            ),
+            s2(vec![vec![0, 2, 1], vec![0, 0, 3]])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_rem() {
+        assert_eq!(
+            Backend::t2_t2_rem::<2, 3>(
+                s2(vec![vec![6, 8, 9], vec![10, 12, 15]]),
+                s2(vec![vec![4, 3, 2], vec![3, 5, 7]])
+            ),
+            s2(vec![vec![2, 2, 1], vec![1, 2, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_add() {
+        assert_eq!(
+            Backend::t3_t0_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![14, 16], vec![17, 20]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_0() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![8, 10], vec![11, 12]],
+                vec![vec![15, 17], vec![18, 21]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_1() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![9, 11], vec![13, 14]],
+                vec![vec![15, 17], vec![19, 22]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_add_along_2() {
+        assert_eq!(
+            Backend::t3_t1_add::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![10, 13], vec![13, 15]],
+                vec![vec![16, 19], vec![19, 23]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_add() {
+        assert_eq!(
+            Backend::t3_t2_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![8, 11], vec![13, 15]],
+                vec![vec![14, 17], vec![19, 23]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_add() {
+        assert_eq!(
+            Backend::t3_t3_add::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![7, 10], vec![12, 14]],
+                vec![vec![17, 20], vec![22, 26]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_sub() {
+        assert_eq!(
+            Backend::t3_t0_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![10, 12], vec![13, 16]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_0() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![4, 6], vec![7, 8]],
+                vec![vec![9, 11], vec![12, 15]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_1() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![3, 5], vec![5, 6]],
+                vec![vec![9, 11], vec![11, 14]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_sub_along_2() {
+        assert_eq!(
+            Backend::t3_t1_sub::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![2, 3], vec![5, 5]],
+                vec![vec![8, 9], vec![11, 13]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_sub() {
+        assert_eq!(
+            Backend::t3_t2_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![4, 5], vec![5, 5]],
+                vec![vec![10, 11], vec![11, 13]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_sub() {
+        assert_eq!(
+            Backend::t3_t3_sub::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![5, 6], vec![6, 6]],
+                vec![vec![7, 8], vec![8, 10]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_mul() {
+        assert_eq!(
+            Backend::t3_t0_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![24, 28], vec![30, 36]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_0() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![12, 16], vec![18, 20]],
+                vec![vec![36, 42], vec![45, 54]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_1() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![18, 24], vec![36, 40]],
+                vec![vec![36, 42], vec![60, 72]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_mul_along_2() {
+        assert_eq!(
+            Backend::t3_t1_mul::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![24, 40], vec![36, 50]],
+                vec![vec![48, 70], vec![60, 90]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_mul() {
+        assert_eq!(
+            Backend::t3_t2_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![12, 24], vec![36, 50]],
+                vec![vec![24, 42], vec![60, 90]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_mul() {
+        assert_eq!(
+            Backend::t3_t3_mul::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![6, 16], vec![27, 40]],
+                vec![vec![60, 84], vec![105, 144]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_div() {
+        assert_eq!(
+            Backend::t3_t0_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![3, 4], vec![4, 5]],
+                vec![vec![6, 7], vec![7, 9]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_0() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![3, 4], vec![4, 5]],
+                vec![vec![4, 4], vec![5, 6]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_1() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![2, 2], vec![2, 2]],
+                vec![vec![4, 4], vec![3, 4]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_div_along_2() {
+        assert_eq!(
+            Backend::t3_t1_div::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![1, 1], vec![2, 2]],
+                vec![vec![3, 2], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_div() {
+        assert_eq!(
+            Backend::t3_t2_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![3, 2], vec![2, 2]],
+                vec![vec![6, 4], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_div() {
+        assert_eq!(
+            Backend::t3_t3_div::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![6, 4], vec![3, 2]],
+                vec![vec![2, 2], vec![2, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t0_rem() {
+        assert_eq!(
+            Backend::t3_t0_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                2
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![1, 0]],
+                vec![vec![0, 0], vec![1, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_0() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![2, 3]),
+                0
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![1, 0]],
+                vec![vec![0, 2], vec![0, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_1() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![3, 4]),
+                1
+            ),
+            s3(vec![
+                vec![vec![0, 2], vec![1, 2]],
+                vec![vec![0, 2], vec![3, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t1_rem_along_2() {
+        assert_eq!(
+            Backend::t3_t1_rem::<2, 2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s1(vec![4, 5]),
+                2
+            ),
+            s3(vec![
+                vec![vec![2, 3], vec![1, 0]],
+                vec![vec![0, 4], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_rem() {
+        assert_eq!(
+            Backend::t3_t2_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s2(vec![vec![2, 3], vec![4, 5]])
+            ),
+            s3(vec![
+                vec![vec![0, 2], vec![1, 0]],
+                vec![vec![0, 2], vec![3, 3]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_rem() {
+        assert_eq!(
+            Backend::t3_t3_rem::<2, 2, 2>(
+                s3(vec![
+                    vec![vec![6, 8], vec![9, 10]],
+                    vec![vec![12, 14], vec![15, 18]]
+                ]),
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ])
+            ),
+            s3(vec![
+                vec![vec![0, 0], vec![0, 2]],
+                vec![vec![2, 2], vec![1, 2]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t0_neg() {
+        assert_eq!(Backend::t0_neg(3), -3);
+    }
+
+    #[test]
+    fn test_t1_neg() {
+        assert_eq!(
+            Backend::t1_neg::<3>(s1(vec![1, -2, 3])),
+            s1(vec![-1, 2, -3])
+        );
+    }
+
+    #[test]
+    fn test_t2_neg() {
+        assert_eq!(
+            Backend::t2_neg::<2, 2>(s2(vec![vec![1, -2], vec![3, 0]])),
+            s2(vec![vec![-1, 2], vec![-3, 0]])
+        );
+    }
+
+    #[test]
+    fn test_t3_neg() {
+        assert_eq!(
+            Backend::t3_neg::<2, 1, 2>(s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]])),
+            s3(vec![vec![vec![-1, 2]], vec![vec![-3, 0]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_map() {
+        assert_eq!(Backend::t0_map(3, |a| a * 2), 6);
+    }
+
+    #[test]
+    fn test_t1_map() {
+        assert_eq!(
+            Backend::t1_map::<3>(s1(vec![1, -2, 3]), |a| a * a),
+            s1(vec![1, 4, 9])
+        );
+    }
+
+    #[test]
+    fn test_t2_map() {
+        assert_eq!(
+            Backend::t2_map::<2, 2>(s2(vec![vec![1, -2], vec![3, 0]]), |a| a + 1),
+            s2(vec![vec![2, -1], vec![4, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t3_map() {
+        assert_eq!(
+            Backend::t3_map::<2, 1, 2>(s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]]), |a| a - 1),
+            s3(vec![vec![vec![0, -3]], vec![vec![2, -1]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_zip_with() {
+        assert_eq!(Backend::t0_zip_with(3, 4, |a, b| a * b), 12);
+    }
+
+    #[test]
+    fn test_t1_zip_with() {
+        assert_eq!(
+            Backend::t1_zip_with::<3>(s1(vec![1, -2, 3]), s1(vec![0, 5, 2]), |a, b| a.max(b)),
+            s1(vec![1, 5, 3])
+        );
+    }
+
+    #[test]
+    fn test_t2_zip_with() {
+        assert_eq!(
+            Backend::t2_zip_with::<2, 2>(
+                s2(vec![vec![1, 2], vec![3, 4]]),
+                s2(vec![vec![5, 6], vec![7, 8]]),
+                |a, b| a * 10 + b
+            ),
+            s2(vec![vec![15, 26], vec![37, 48]])
+        );
+    }
+
+    #[test]
+    fn test_t3_zip_with() {
+        assert_eq!(
+            Backend::t3_zip_with::<2, 1, 2>(
+                s3(vec![vec![vec![1, -2]], vec![vec![3, 0]]]),
+                s3(vec![vec![vec![1, 1]], vec![vec![1, 1]]]),
+                |a, b| a - b
+            ),
+            s3(vec![vec![vec![0, -3]], vec![vec![2, -1]]])
+        );
+    }
+
+    #[test]
+    fn test_t0_fold() {
+        assert_eq!(Backend::t0_fold(3, 1, |acc, a| acc + a), 4);
+    }
+
+    #[test]
+    fn test_t1_fold() {
+        assert_eq!(
+            Backend::t1_fold::<3, _>(s1(vec![1, 2, 3]), 0, |acc, a| acc * 10 + a),
+            123
+        );
+    }
+
+    #[test]
+    fn test_t2_fold() {
+        assert_eq!(
+            Backend::t2_fold::<2, 2, _>(s2(vec![vec![1, 2], vec![3, 4]]), 0, |acc, a| acc * 10 + a),
+            1234
+        );
+    }
+
+    #[test]
+    fn test_t3_fold() {
+        assert_eq!(
+            Backend::t3_fold::<2, 1, 2, _>(
+                s3(vec![vec![vec![1, 2]], vec![vec![3, 4]]]),
+                Vec::new(),
+                |mut acc, a| {
+                    acc.push(a);
+                    acc
+                }
+            ),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_t1_sum() {
+        assert_eq!(Backend::t1_sum::<3>(s1(vec![1, 2, 3])), 6);
+    }
+
+    #[test]
+    fn test_t1_prod() {
+        assert_eq!(Backend::t1_prod::<3>(s1(vec![2, 3, 4])), 24);
+    }
+
+    #[test]
+    fn test_t1_min() {
+        assert_eq!(Backend::t1_min::<3>(s1(vec![3, 1, 2])), 1);
+    }
+
+    #[test]
+    fn test_t1_max() {
+        assert_eq!(Backend::t1_max::<3>(s1(vec![3, 1, 2])), 3);
+    }
+
+    #[test]
+    fn test_t1_mean() {
+        assert_eq!(Backend::t1_mean::<3>(s1(vec![1.0, 2.0, 6.0])), 3.0);
+    }
+
+    #[test]
+    fn test_t1_argmin() {
+        assert_eq!(Backend::t1_argmin::<4>(s1(vec![3, 1, 2, 1])), 1);
+    }
+
+    #[test]
+    fn test_t1_argmax() {
+        assert_eq!(Backend::t1_argmax::<4>(s1(vec![3, 1, 3, 2])), 0);
+    }
+
+    #[test]
+    fn test_t2_sum_axis() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_sum_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![5, 7, 9])
+        );
+        assert_eq!(Backend::t2_sum_axis::<2, 3, 2>(a, 1), s1(vec![6, 15]));
+    }
+
+    #[test]
+    fn test_t2_prod_axis() {
+        let a = s2(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            Backend::t2_prod_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![4, 10, 18])
+        );
+        assert_eq!(Backend::t2_prod_axis::<2, 3, 2>(a, 1), s1(vec![6, 120]));
+    }
+
+    #[test]
+    fn test_t2_min_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_min_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![1, 2, 3])
+        );
+        assert_eq!(Backend::t2_min_axis::<2, 3, 2>(a, 1), s1(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_t2_max_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_max_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![4, 5, 6])
+        );
+        assert_eq!(Backend::t2_max_axis::<2, 3, 2>(a, 1), s1(vec![5, 6]));
+    }
+
+    #[test]
+    fn test_t2_mean_axis() {
+        let a = s2(vec![vec![1.0, 2.0, 3.0], vec![5.0, 6.0, 7.0]]);
+        assert_eq!(
+            Backend::t2_mean_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![3.0, 4.0, 5.0])
+        );
+        assert_eq!(Backend::t2_mean_axis::<2, 3, 2>(a, 1), s1(vec![2.0, 6.0]));
+    }
+
+    #[test]
+    fn test_t2_argmin_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_argmin_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![0, 1, 0])
+        );
+        assert_eq!(Backend::t2_argmin_axis::<2, 3, 2>(a, 1), s1(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_t2_argmax_axis() {
+        let a = s2(vec![vec![1, 5, 3], vec![4, 2, 6]]);
+        assert_eq!(
+            Backend::t2_argmax_axis::<2, 3, 3>(a.clone(), 0),
+            s1(vec![1, 0, 1])
+        );
+        assert_eq!(Backend::t2_argmax_axis::<2, 3, 2>(a, 1), s1(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_t3_sum_axis() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![6, 8], vec![10, 12]])
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![4, 6], vec![12, 14]])
+        );
+        assert_eq!(
+            Backend::t3_sum_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![3, 7], vec![11, 15]])
+        );
+    }
+
+    #[test]
+    fn test_t3_prod_axis() {
+        let a = s3(vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]],
+        ]);
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![5, 12], vec![21, 32]])
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![3, 8], vec![35, 48]])
+        );
+        assert_eq!(
+            Backend::t3_prod_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![2, 12], vec![30, 56]])
+        );
+    }
+
+    #[test]
+    fn test_t3_min_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![1, 6], vec![2, 4]])
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![1, 4], vec![2, 6]])
+        );
+        assert_eq!(
+            Backend::t3_min_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1, 3], vec![5, 2]])
+        );
+    }
+
+    #[test]
+    fn test_t3_max_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![5, 8], vec![3, 7]])
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![3, 8], vec![5, 7]])
+        );
+        assert_eq!(
+            Backend::t3_max_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![8, 4], vec![6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_t3_mean_axis() {
+        let a = s3(vec![
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+            vec![vec![5.0, 6.0], vec![7.0, 8.0]],
+        ]);
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![3.0, 4.0], vec![5.0, 6.0]])
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![2.0, 3.0], vec![6.0, 7.0]])
+        );
+        assert_eq!(
+            Backend::t3_mean_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1.5, 3.5], vec![5.5, 7.5]])
+        );
+    }
+
+    #[test]
+    fn test_t3_argmin_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![0, 1], vec![1, 0]])
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![0, 1], vec![1, 0]])
+        );
+        assert_eq!(
+            Backend::t3_argmin_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![0, 0], vec![0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_t3_argmax_axis() {
+        let a = s3(vec![
+            vec![vec![1, 8], vec![3, 4]],
+            vec![vec![5, 6], vec![2, 7]],
+        ]);
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 0),
+            s2(vec![vec![1, 0], vec![0, 1]])
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a.clone(), 1),
+            s2(vec![vec![1, 0], vec![0, 1]])
+        );
+        assert_eq!(
+            Backend::t3_argmax_axis::<2, 2, 2, 2, 2>(a, 2),
+            s2(vec![vec![1, 1], vec![1, 1]])
+        );
+    }
+
+    #[test]
+    fn test_t1_t1_dot() {
+        assert_eq!(
+            Backend::t1_t1_dot::<3>(s1(vec![1, 2, 3]), s1(vec![4, 5, 6])),
+            32
+        );
+    }
+
+    #[test]
+    fn test_t2_t1_matvec() {
+        assert_eq!(
+            Backend::t2_t1_matvec::<2, 3>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s1(vec![2, 3, 4])
+            ),
+            s1(vec![20, 47])
+        );
+    }
+
+    #[test]
+    fn test_t1_t2_vecmat() {
+        assert_eq!(
+            Backend::t1_t2_vecmat::<2, 3>(s1(vec![2, 3]), s2(vec![vec![1, 2, 3], vec![4, 5, 6]])),
+            s1(vec![14, 19, 24])
+        );
+    }
+
+    #[test]
+    fn test_t2_t2_matmul() {
+        assert_eq!(
+            Backend::t2_t2_matmul::<2, 3, 2>(
+                s2(vec![vec![1, 2, 3], vec![4, 5, 6]]),
+                s2(vec![vec![2, 3], vec![4, 5], vec![6, 7]])
+            ),
+            s2(vec![vec![28, 34], vec![64, 79]])
+        );
+    }
+
+    #[test]
+    fn test_t3_t2_matmul() {
+        assert_eq!(
+            Backend::t3_t2_matmul::<2, 2, 2, 3>(
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ]),
+                s2(vec![vec![1, 0, 2], vec![0, 1, 3]])
+            ),
+            s3(vec![
+                vec![vec![1, 2, 8], vec![3, 4, 18]],
+                vec![vec![5, 6, 28], vec![7, 8, 38]]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_t3_t3_bmm() {
+        assert_eq!(
+            Backend::t3_t3_bmm::<2, 2, 2, 1>(
+                s3(vec![
+                    vec![vec![1, 2], vec![3, 4]],
+                    vec![vec![5, 6], vec![7, 8]]
+                ]),
+                s3(vec![vec![vec![1], vec![1]], vec![vec![2], vec![-1]]])
+            ),
+            s3(vec![vec![vec![3], vec![7]], vec![vec![4], vec![6]]])
+        );
+    }
+
+    #[test]
+    fn test_kernels_on_views() {
+        let a = Backend::t2_transpose::<2, 2>(Backend::t2_from_array([[1, 2], [3, 4]]));
+        let b = Backend::t2_from_array([[1, 1], [2, 2]]);
+        assert_eq!(
+            Backend::t2_t2_add::<2, 2>(a.clone(), b.clone()),
+            Backend::t2_from_array([[2, 4], [4, 6]])
+        );
+        assert_eq!(
+            Backend::t2_t2_matmul::<2, 2, 2>(a.clone(), b),
+            Backend::t2_from_array([[7, 7], [10, 10]])
+        );
+        assert_eq!(
+            Backend::t2_sum_axis::<2, 2, 2>(a, 1),
+            Backend::t1_from_array([4, 6])
+        );
+    }
+
+    fn reductions<const THREADS: usize>(a: &[f32]) -> (Vec<f32>, f32, f32) {
+        type B<const THREADS: usize> = super::Backend<THREADS, 64>;
+        let v = Strided::new(a.to_vec(), [10_000]);
+        let m = Strided::new(a.to_vec(), [100, 100]);
+        (
+            B::<THREADS>::t2_sum_axis::<100, 100, 100>(m, 0).into_vec(),
+            B::<THREADS>::t1_sum::<10_000>(v.clone()),
+            B::<THREADS>::t1_t1_dot::<10_000>(v.clone(), v),
+        )
+    }
+
+    #[test]
+    fn test_reductions_match_across_thread_counts() {
+        let a: Vec<f32> = (0..10_000).map(|i| 1.0 / (i + 1) as f32).collect();
+        let expected = reductions::<1>(&a);
+        assert_eq!(reductions::<2>(&a), expected);
+        assert_eq!(reductions::<3>(&a), expected);
+        assert_eq!(reductions::<8>(&a), expected);
+    }
+
+    #[test]
+    fn test_argmin_picks_first_across_blocks() {
+        let a = Strided::new(vec![3, 1, 2, 1, 1, 0, 4, 0], [8]);
+        assert_eq!(super::Backend::<4, 2>::t1_argmin::<8>(a.clone()), 5);
+        assert_eq!(super::Backend::<4, 2>::t1_argmax::<8>(a), 6);
+    }
+}
@@ -0,0 +1,42 @@
+//! Times the naive matmul loop against the packed GEMM on a 512x512 `f32` product.
+//!
+//! Run with `cargo run --release --bin gemm_bench`.
+
+use std::time::{Duration, Instant};
+
+use tensr::backend::gemm::{self, Blocking};
+
+const SIZE: usize = 512;
+const RUNS: u32 = 3;
+
+fn time(mut f: impl FnMut() -> Vec<f32>) -> (Duration, Vec<f32>) {
+    let mut result = f();
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        result = f();
+    }
+    (start.elapsed() / RUNS, result)
+}
+
+fn main() {
+    let shape = (SIZE, SIZE, SIZE);
+    // Quarter-integers small enough that every sum is exact, whatever the order.
+    let a: Vec<f32> = (0..SIZE * SIZE).map(|i| (i % 17) as f32 * 0.25).collect();
+    let b: Vec<f32> = (0..SIZE * SIZE).map(|i| (i % 13) as f32 - 6.0).collect();
+
+    let (naive, expected) = time(|| gemm::naive(&a, &b, shape));
+    println!("naive               {naive:>10.2?}");
+
+    let tuned = Blocking::autotune();
+    for (name, blocking) in [
+        ("packed (default)", Blocking::default()),
+        ("packed (tuned)", tuned),
+    ] {
+        let (elapsed, result) = time(|| gemm::gemm(&a, &b, shape, blocking));
+        assert_eq!(result, expected);
+        println!(
+            "{name:<19} {elapsed:>10.2?}  {:>5.1}x  {blocking:?}",
+            naive.as_secs_f64() / elapsed.as_secs_f64()
+        );
+    }
+}
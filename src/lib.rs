@@ -1,5 +1,8 @@
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs)]
+#![feature(portable_simd)]
 
+pub mod autodiff;
 pub mod backend;
+pub mod num;
 pub mod tensor;
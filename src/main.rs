@@ -1,10 +1,13 @@
-use tensr::tensor::{Matrix, Scalar, Tensor, Vector};
+use tensr::{
+    tensor,
+    tensor::{Matrix, Tensor},
+};
 
 fn main() {
     let a = Matrix::ones();
     let b = Matrix::identity();
     let c = a.matmul(b);
-    let d = c + Matrix::<f32, 3, 3>::identity();
+    let _ = c + Matrix::<f32, 3, 3>::identity();
 
     let e = Matrix::<f32, 3, 5>::ones();
     println!("{:?}", e.shape());
@@ -12,5 +15,8 @@ fn main() {
     let a1 = Matrix::<f32, 3, 5>::zeros().permute::<1, 0>();
 
     let b1 = Matrix::zeros();
-    let c1: Matrix<f32, 5, 3> = a1 + b1;
+    let _: Matrix<f32, 5, 3> = a1 + b1;
+
+    let f: Matrix<f32, 2, 3> = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    println!("{:?}", f.shape());
 }
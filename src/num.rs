@@ -0,0 +1,256 @@
+//! Numeric traits for tensor elements.
+//!
+//! Kernels ask for the least they need: [`Zero`] or [`One`] to build sums, products and
+//! constant tensors, [`Signed`] for negation and magnitudes, and [`Float`] for the
+//! transcendental functions.
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The additive identity.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+}
+
+/// The multiplicative identity.
+pub trait One: Sized {
+    fn one() -> Self;
+
+    /// Converts `n`, or returns `None` if it does not fit, as 256 does not in `u8`.
+    ///
+    /// The default builds `n` from its binary digits by doubling and adding one, in `O(log n)`
+    /// additions, and cannot detect overflow; the primitive types check it.
+    fn from_usize(n: usize) -> Option<Self>
+    where
+        Self: Zero + Add<Output = Self> + Copy,
+    {
+        let n = (0..usize::BITS - n.leading_zeros())
+            .rev()
+            .fold(Self::zero(), |acc, bit| {
+                let acc = acc + acc;
+                if (n >> bit) & 1 == 1 {
+                    acc + Self::one()
+                } else {
+                    acc
+                }
+            });
+        Some(n)
+    }
+}
+
+/// Types with the usual arithmetic and an ordering, such as the primitive integers and floats.
+pub trait Num:
+    Zero
+    + One
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+}
+
+impl<T> Num for T where
+    T: Zero
+        + One
+        + Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+{
+}
+
+/// Numbers that can be negative.
+pub trait Signed: Num + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    /// Returns `-1`, `0` or `1` according to the sign, or NaN for NaN.
+    fn signum(self) -> Self;
+}
+
+/// Floating-point numbers.
+pub trait Float: Signed {
+    fn epsilon() -> Self;
+    fn infinity() -> Self;
+    fn neg_infinity() -> Self;
+    fn nan() -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    /// Converts `v`, rounding to the nearest representable value.
+    fn from_f64(v: f64) -> Self;
+
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tanh(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+}
+
+macro_rules! impl_num {
+    ($convert:expr; $($t:ty),*) => {$(
+        impl Zero for $t {
+            fn zero() -> Self {
+                0 as $t
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                1 as $t
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                $convert(n)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed_int {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                if self == 0.0 {
+                    0.0
+                } else {
+                    <$t>::signum(self)
+                }
+            }
+        }
+
+        impl Float for $t {
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn infinity() -> Self {
+                $t::INFINITY
+            }
+
+            fn neg_infinity() -> Self {
+                $t::NEG_INFINITY
+            }
+
+            fn nan() -> Self {
+                $t::NAN
+            }
+
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn tanh(self) -> Self {
+                <$t>::tanh(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                <$t>::powi(self, n)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+        }
+    )*};
+}
+
+impl_num!(|n: usize| n.try_into().ok(); u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+impl_num!(Some; usize);
+impl_num!(|n: usize| Some(n as _); f32, f64);
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+impl_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum<T: Num>(a: &[T]) -> T {
+        a.iter().fold(T::zero(), |acc, &a| acc + a)
+    }
+
+    #[test]
+    fn test_identities() {
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+        assert_eq!(sum::<f32>(&[]), 0.0);
+        assert_eq!(i64::one() * 7, 7);
+    }
+
+    #[test]
+    fn test_from_usize() {
+        assert_eq!(u8::from_usize(255), Some(255));
+        assert_eq!(u8::from_usize(256), None);
+        assert_eq!(i8::from_usize(128), None);
+        assert_eq!(f32::from_usize(1 << 24), Some(16777216.0));
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(Signed::abs(-3i32), 3);
+        assert_eq!(Signed::signum(-3i32), -1);
+        assert_eq!(Signed::signum(0.0f64), 0.0);
+        assert_eq!(Signed::signum(-2.5f32), -1.0);
+        assert!(Signed::signum(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(Float::sqrt(9.0f64), 3.0);
+        assert_eq!(Float::powi(2.0f32, 3), 8.0);
+        assert_eq!(<f32 as Float>::from_f64(0.5), 0.5);
+        assert!(<f64 as Float>::nan().is_nan());
+        assert!(!Float::is_finite(<f32 as Float>::neg_infinity()));
+        assert_eq!(Float::ln(Float::exp(1.5f64)), 1.5);
+    }
+}
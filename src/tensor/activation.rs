@@ -0,0 +1,75 @@
+//! Elementwise activation functions, as applied by the tensor methods of the same names.
+
+use crate::num::{Float, Num};
+
+/// `max(x, 0)`.
+pub fn relu<T: Num>(x: T) -> T {
+    if x > T::zero() {
+        x
+    } else {
+        T::zero()
+    }
+}
+
+/// The logistic function `1 / (1 + e^-x)`, evaluated so that `e^x` never overflows.
+pub fn sigmoid<T: Float>(x: T) -> T {
+    if x >= T::zero() {
+        T::one() / (T::one() + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (T::one() + e)
+    }
+}
+
+/// The Gaussian error linear unit, using the usual `tanh` approximation
+/// `x / 2 * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 x^3)))`.
+pub fn gelu<T: Float>(x: T) -> T {
+    let k = T::from_f64((2.0 / std::f64::consts::PI).sqrt());
+    let inner = k * (x + T::from_f64(0.044715) * x * x * x);
+    T::from_f64(0.5) * x * (T::one() + inner.tanh())
+}
+
+/// `ln(1 + e^x)`, rewritten as `max(x, 0) + ln(1 + e^-|x|)` so that large inputs neither
+/// overflow nor lose precision.
+pub fn softplus<T: Float>(x: T) -> T {
+    relu(x) + (T::one() + (-x.abs()).exp()).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_relu() {
+        assert_eq!(relu(-3), 0);
+        assert_eq!(relu(2.5), 2.5);
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        assert_eq!(sigmoid(0.0), 0.5);
+        assert_close(sigmoid(2.0), 1.0 / (1.0 + (-2.0f64).exp()));
+        assert_close(sigmoid(-2.0) + sigmoid(2.0), 1.0);
+        assert_eq!(sigmoid(-1000.0), 0.0);
+        assert_eq!(sigmoid(1000.0f32), 1.0);
+    }
+
+    #[test]
+    fn test_gelu() {
+        assert_eq!(gelu(0.0), 0.0);
+        assert_close(gelu(1.0), 0.8411919906082768);
+        assert_close(gelu(-1.0), -0.15880800939172324);
+    }
+
+    #[test]
+    fn test_softplus() {
+        assert_close(softplus(0.0), 2.0f64.ln());
+        assert_close(softplus(1.5), (1.0 + 1.5f64.exp()).ln());
+        assert_eq!(softplus(1000.0), 1000.0);
+        assert_eq!(softplus(-1000.0), 0.0);
+    }
+}
@@ -0,0 +1,451 @@
+//! Factorizations of matrices, and the solvers built on them.
+//!
+//! The algorithms run on the row-major elements, so every backend gets them for the cost of
+//! one copy in and out.
+
+use std::{cmp::Ordering, marker::PhantomData};
+
+use crate::{
+    backend::{AutoSelectBackend, Backend},
+    num::Float,
+    tensor::{Matrix, Vector},
+};
+
+/// The LU decomposition `PA = LU` of a square matrix, computed with partial pivoting by
+/// [`Matrix::lu`].
+///
+/// `L` is unit lower triangular and `U` upper triangular. Singular matrices still factor, but
+/// cannot be inverted or solved against. A pivot of `U` counts as zero once it is within
+/// `D * epsilon * max|a_ij|` of it, the rounding error elimination can leave behind.
+#[derive(Clone, Debug)]
+pub struct Lu<T, const D: usize, B: Backend<T> = AutoSelectBackend> {
+    /// `L` below the diagonal, its unit diagonal left implicit, and `U` on and above it.
+    factors: Vec<T>,
+    permutation: [usize; D],
+    swaps: usize,
+    /// Pivots no larger than this in magnitude are treated as zero.
+    tolerance: T,
+    backend: PhantomData<B>,
+}
+
+impl<T: Float, const D: usize, B: Backend<T>> Lu<T, D, B> {
+    pub(crate) fn new(mut a: Vec<T>) -> Self {
+        let scale = a
+            .iter()
+            .fold(T::zero(), |m, x| if x.abs() > m { x.abs() } else { m });
+        let tolerance = T::from_f64(D as f64) * T::epsilon() * scale;
+        let mut permutation = std::array::from_fn(|i| i);
+        let mut swaps = 0;
+        for k in 0..D {
+            // Pivoting on the largest remaining entry keeps the multipliers at most 1 in size.
+            let p = (k..D).fold(k, |p, i| {
+                if a[i * D + k].abs() > a[p * D + k].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if p != k {
+                for j in 0..D {
+                    a.swap(k * D + j, p * D + j);
+                }
+                permutation.swap(k, p);
+                swaps += 1;
+            }
+            let pivot = a[k * D + k];
+            if pivot.abs() <= tolerance {
+                // The column is already eliminated to within the tolerance, so its multipliers
+                // are zero rather than the leftover entries.
+                for i in k + 1..D {
+                    a[i * D + k] = T::zero();
+                }
+                continue;
+            }
+            for i in k + 1..D {
+                let factor = a[i * D + k] / pivot;
+                a[i * D + k] = factor;
+                for j in k + 1..D {
+                    a[i * D + j] = a[i * D + j] - factor * a[k * D + j];
+                }
+            }
+        }
+        Self {
+            factors: a,
+            permutation,
+            swaps,
+            tolerance,
+            backend: PhantomData,
+        }
+    }
+
+    /// The unit lower triangular factor.
+    pub fn l(&self) -> Matrix<T, D, D, B> {
+        self.triangle(|i, j| match i.cmp(&j) {
+            Ordering::Less => Some(T::zero()),
+            Ordering::Equal => Some(T::one()),
+            Ordering::Greater => None,
+        })
+    }
+
+    /// The upper triangular factor.
+    pub fn u(&self) -> Matrix<T, D, D, B> {
+        self.triangle(|i, j| if i > j { Some(T::zero()) } else { None })
+    }
+
+    /// Row `i` of `PA` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> [usize; D] {
+        self.permutation
+    }
+
+    pub fn det(&self) -> T {
+        let det = (0..D).fold(T::one(), |det, i| det * self.factors[i * D + i]);
+        if self.swaps.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..D).any(|i| self.factors[i * D + i].abs() <= self.tolerance)
+    }
+
+    /// Solves `Ax = b`, or returns `None` if `A` is singular.
+    pub fn solve(&self, b: Vector<T, D, B>) -> Option<Vector<T, D, B>> {
+        if self.is_singular() {
+            return None;
+        }
+        let b = B::t1_into_vec(b.repr);
+        Some(Vector {
+            repr: B::t1_from_vec(self.substitute(|i| b[self.permutation[i]])),
+            shape: D,
+        })
+    }
+
+    /// Returns `A^-1`, or `None` if `A` is singular.
+    pub fn inverse(&self) -> Option<Matrix<T, D, D, B>> {
+        if self.is_singular() {
+            return None;
+        }
+        let mut inverse = vec![T::zero(); D * D];
+        for j in 0..D {
+            let column = self.substitute(|i| {
+                if self.permutation[i] == j {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            });
+            for (i, x) in column.into_iter().enumerate() {
+                inverse[i * D + j] = x;
+            }
+        }
+        Some(Matrix {
+            repr: B::t2_from_vec(inverse),
+            shape: (D, D),
+        })
+    }
+
+    /// Solves `LUx = y` by forward then back substitution, where `y[i]` is `pb(i)`.
+    fn substitute(&self, pb: impl Fn(usize) -> T) -> Vec<T> {
+        let a = &self.factors;
+        let mut x: Vec<T> = Vec::with_capacity(D);
+        for i in 0..D {
+            let y = (0..i).fold(pb(i), |y, j| y - a[i * D + j] * x[j]);
+            x.push(y);
+        }
+        for i in (0..D).rev() {
+            let y = (i + 1..D).fold(x[i], |y, j| y - a[i * D + j] * x[j]);
+            x[i] = y / a[i * D + i];
+        }
+        x
+    }
+
+    fn triangle(&self, fixed: impl Fn(usize, usize) -> Option<T>) -> Matrix<T, D, D, B> {
+        let data = (0..D * D)
+            .map(|l| fixed(l / D, l % D).unwrap_or(self.factors[l]))
+            .collect();
+        Matrix {
+            repr: B::t2_from_vec(data),
+            shape: (D, D),
+        }
+    }
+}
+
+/// Factors the row-major `m x n` matrix `a` as `QR` with Householder reflections, returning the
+/// row-major `m x m` orthogonal `Q` and `m x n` upper triangular `R`.
+///
+/// Signs are chosen so that the diagonal of `R` is non-negative, which makes the factors unique
+/// when `a` has full column rank.
+pub(crate) fn qr<T: Float>(mut r: Vec<T>, m: usize, n: usize) -> (Vec<T>, Vec<T>) {
+    let mut q: Vec<T> = (0..m * m)
+        .map(|l| if l / m == l % m { T::one() } else { T::zero() })
+        .collect();
+    for k in 0..n.min(m.saturating_sub(1)) {
+        // Reflect column k below the diagonal onto a multiple of e_k, choosing the sign of the
+        // target that avoids cancellation in `v = x - target`.
+        let mut v: Vec<T> = (k..m).map(|i| r[i * n + k]).collect();
+        let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+        if norm == T::zero() {
+            continue;
+        }
+        v[0] = if v[0] < T::zero() {
+            v[0] - norm
+        } else {
+            v[0] + norm
+        };
+        let vv = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+        let two = T::one() + T::one();
+
+        // R = H R and Q = Q H, with H = I - 2 v v^T / (v^T v).
+        for j in k..n {
+            let s = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * r[(k + i) * n + j]);
+            let s = two * s / vv;
+            for (i, &vi) in v.iter().enumerate() {
+                r[(k + i) * n + j] = r[(k + i) * n + j] - s * vi;
+            }
+        }
+        for row in q.chunks_exact_mut(m) {
+            let s = (0..v.len()).fold(T::zero(), |acc, i| acc + row[k + i] * v[i]);
+            let s = two * s / vv;
+            for (i, &vi) in v.iter().enumerate() {
+                row[k + i] = row[k + i] - s * vi;
+            }
+        }
+        for i in k + 1..m {
+            r[i * n + k] = T::zero();
+        }
+    }
+    for k in 0..n.min(m) {
+        if r[k * n + k] < T::zero() {
+            for x in &mut r[k * n..(k + 1) * n] {
+                *x = -*x;
+            }
+            for row in q.chunks_exact_mut(m) {
+                row[k] = -row[k];
+            }
+        }
+    }
+    (q, r)
+}
+
+/// Returns the `x` minimizing `|ax - b|` for a row-major `m x n` matrix `a` with `m >= n`, by
+/// solving `R x = Q^T b` on the leading `n` rows.
+///
+/// Returns `None` if `a` is rank deficient, taking a diagonal entry of `R` within
+/// `m * epsilon * max|r_ii|` of zero as a dependent column.
+pub(crate) fn lstsq<T: Float>(a: Vec<T>, b: Vec<T>, m: usize, n: usize) -> Option<Vec<T>> {
+    assert!(
+        m >= n,
+        "least squares needs at least as many rows as columns"
+    );
+    let (q, r) = qr(a, m, n);
+    let scale = (0..n).fold(T::zero(), |s, i| {
+        let d = r[i * n + i].abs();
+        if d > s {
+            d
+        } else {
+            s
+        }
+    });
+    let tolerance = T::from_f64(m as f64) * T::epsilon() * scale;
+    if (0..n).any(|i| r[i * n + i].abs() <= tolerance) {
+        return None;
+    }
+    let mut x: Vec<T> = (0..n)
+        .map(|j| (0..m).fold(T::zero(), |acc, i| acc + q[i * m + j] * b[i]))
+        .collect();
+    for i in (0..n).rev() {
+        let y = (i + 1..n).fold(x[i], |y, j| y - r[i * n + j] * x[j]);
+        x[i] = y / r[i * n + i];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{array, basic};
+
+    fn assert_close<const D0: usize, const D1: usize, B: Backend<f64>>(
+        a: Matrix<f64, D0, D1, B>,
+        b: Matrix<f64, D0, D1, B>,
+    ) {
+        let (a, b) = (B::t2_into_vec(a.repr), B::t2_into_vec(b.repr));
+        for (a, b) in a.into_iter().zip(b) {
+            assert!((a - b).abs() < 1e-9, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_lu() {
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let lu = a.clone().lu();
+        // The first pivot is 4 from row 1; the tie between the 4s left in column 1 keeps row 0.
+        assert_eq!(lu.permutation(), [1, 0, 2]);
+        assert_close(
+            lu.l(),
+            Matrix::from_array([[1.0, 0.0, 0.0], [0.5, 1.0, 0.0], [-0.5, 1.0, 1.0]]),
+        );
+        assert_close(
+            lu.u(),
+            Matrix::from_array([[4.0, -6.0, 0.0], [0.0, 4.0, 1.0], [0.0, 0.0, 1.0]]),
+        );
+
+        let p = lu.permutation();
+        let pa = Matrix::from_array(p.map(|i| [0, 1, 2].map(|j| a[(i, j)])));
+        assert_close(lu.l().matmul(lu.u()), pa);
+    }
+
+    #[test]
+    fn test_det() {
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        assert!((a.det() + 16.0).abs() < 1e-12);
+
+        let swapped: Matrix<f64, 2, 2, basic::Backend> =
+            Matrix::from_array([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(swapped.det(), -1.0);
+        assert_eq!(Matrix::<f32, 4, 4>::identity().det(), 1.0);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<f64, 3, 3, array::Backend> =
+            Matrix::from_array([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let x = a.solve(Vector::from_array([7.0, -8.0, 18.0])).unwrap();
+        for (x, e) in x.repr.into_iter().zip([1.0, 2.0, 3.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a: Matrix<f64, 2, 2> = Matrix::from_array([[4.0, 7.0], [2.0, 6.0]]);
+        assert_close(
+            a.inverse().unwrap(),
+            Matrix::from_array([[0.6, -0.7], [-0.2, 0.4]]),
+        );
+
+        let a: Matrix<f64, 6, 6, basic::Backend> = Matrix::from_array(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                let x = ((i * 7 + j * 3) % 11) as f64 - 5.0;
+                if i == j {
+                    x + 10.0
+                } else {
+                    x
+                }
+            })
+        }));
+        assert_close(a.clone().matmul(a.inverse().unwrap()), Matrix::identity());
+    }
+
+    #[test]
+    fn test_singular() {
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.det(), 0.0);
+        assert!(lu.inverse().is_none());
+        assert!(lu.solve(Vector::from_array([1.0, 2.0, 3.0])).is_none());
+    }
+
+    #[test]
+    fn test_singular_after_rounding() {
+        // Elimination leaves a pivot of about 1e-16 here rather than an exact zero.
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let lu = a.clone().lu();
+        assert!(lu.u()[(2, 2)] != 0.0);
+        assert!(lu.is_singular());
+        assert!(lu.inverse().is_none());
+        assert!(lu.solve(Vector::from_array([1.0, 2.0, 3.0])).is_none());
+        assert!(a.inverse().is_none());
+
+        let a: Matrix<f32, 3, 3> =
+            Matrix::from_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert!(a.lu().is_singular());
+    }
+
+    #[test]
+    fn test_singular_factors() {
+        // The second pivot, 1000, is below the tolerance set by the 1e20 entry, so column 1 is
+        // skipped with 500 still under it.
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[1e20, 0.0, 0.0], [0.0, 1000.0, 1.0], [0.0, 500.0, 3.0]]);
+        let lu = a.clone().lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.l()[(2, 1)], 0.0);
+        let p = lu.permutation();
+        let product = lu.l().matmul(lu.u());
+        let tolerance = 3.0 * f64::EPSILON * 1e20;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((product[(i, j)] - a[(p[i], j)]).abs() <= tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr() {
+        let a: Matrix<f64, 3, 2> = Matrix::from_array([[3.0, -6.0], [4.0, -8.0], [0.0, 1.0]]);
+        let (q, r) = a.clone().qr();
+        // Gram-Schmidt by hand: q0 = a0 / 5, and a1 + 10 q0 = e2.
+        assert_close(
+            r.clone(),
+            Matrix::from_array([[5.0, -10.0], [0.0, 1.0], [0.0, 0.0]]),
+        );
+        for i in 0..3 {
+            assert!((q[(i, 0)] - [0.6, 0.8, 0.0][i]).abs() < 1e-12);
+            assert!((q[(i, 1)] - [0.0, 0.0, 1.0][i]).abs() < 1e-12);
+        }
+        assert_close(q.clone().matmul(r), a);
+        assert_close(q.clone().permute::<1, 0>().matmul(q), Matrix::identity());
+    }
+
+    #[test]
+    fn test_qr_wide() {
+        let a: Matrix<f64, 2, 3, basic::Backend> =
+            Matrix::from_array([[0.0, 2.0, 1.0], [-1.0, 0.0, 3.0]]);
+        let (q, r) = a.clone().qr();
+        // The first column is -e1, so Q swaps the rows and flips the sign of the second.
+        assert_close(q.clone(), Matrix::from_array([[0.0, 1.0], [-1.0, 0.0]]));
+        assert_close(
+            r.clone(),
+            Matrix::from_array([[1.0, 0.0, -3.0], [0.0, 2.0, 1.0]]),
+        );
+        assert_close(q.matmul(r), a);
+    }
+
+    #[test]
+    fn test_lstsq() {
+        // Fitting y = c0 + c1 t to (0, 1), (1, 3), (2, 4), (3, 4); the normal equations
+        // [[4, 6], [6, 14]] c = [12, 23] give c = (1.5, 1).
+        let a: Matrix<f64, 4, 2, array::Backend> =
+            Matrix::from_array([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let c = a.lstsq(Vector::from_array([1.0, 3.0, 4.0, 4.0])).unwrap();
+        assert!((c[0] - 1.5).abs() < 1e-12);
+        assert!((c[1] - 1.0).abs() < 1e-12);
+
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from_array([[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]);
+        let x = a.lstsq(Vector::from_array([7.0, -8.0, 18.0])).unwrap();
+        for (i, e) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            assert!((x[i] - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lstsq_rank_deficient() {
+        // The second column is 0.1 times the first, so R's last diagonal entry is rounding error.
+        let a: Matrix<f64, 3, 2> = Matrix::from_array([[1.0, 0.1], [2.0, 0.2], [3.0, 0.3]]);
+        let (_, r) = a.clone().qr();
+        assert!(r[(1, 1)] != 0.0);
+        assert!(a.lstsq(Vector::from_array([1.0, 2.0, 3.0])).is_none());
+
+        let a: Matrix<f64, 2, 2, basic::Backend> = Matrix::from_array([[0.0; 2]; 2]);
+        assert!(a.lstsq(Vector::from_array([1.0, 1.0])).is_none());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod activation;
+pub mod linalg;
 pub mod tensor0;
 pub mod tensor1;
 pub mod tensor2;
@@ -8,6 +10,8 @@ pub use tensor1::Tensor1 as Vector;
 pub use tensor2::Tensor2 as Matrix;
 pub use tensor3::Tensor3;
 
+use crate::num::{One, Zero};
+
 pub trait Tensor {
     type Shape;
     type DataType;
@@ -16,11 +20,63 @@ pub trait Tensor {
 
     fn zeros() -> Self
     where
-        Self::DataType: From<u8> + Copy;
+        Self::DataType: Zero + Copy;
     fn ones() -> Self
     where
-        Self::DataType: From<u8> + Copy;
+        Self::DataType: One + Copy;
 }
 
 pub type Index = usize;
 pub type Dimension = usize;
+
+/// Builds a tensor from nested array literals, inferring its rank and shape from the nesting.
+///
+/// There is no scalar form, since a single element already builds a one-element [`Vector`];
+/// use [`Scalar::new`] instead.
+///
+/// ```
+/// use tensr::{
+///     tensor,
+///     tensor::{Matrix, Tensor3, Vector},
+/// };
+///
+/// let v: Vector<i32, 3> = tensor![1, 2, 3];
+/// let m: Matrix<i32, 2, 3> = tensor![[1, 2, 3], [4, 5, 6]];
+/// let t: Tensor3<i32, 2, 1, 2> = tensor![[[1, 2]], [[3, 4]]];
+/// ```
+#[macro_export]
+macro_rules! tensor {
+    ($([$([$($x:expr),* $(,)?]),+ $(,)?]),+ $(,)?) => {
+        $crate::tensor::Tensor3::<_, _, _, _>::from_array([$([$([$($x),*]),+]),+])
+    };
+    ($([$($x:expr),* $(,)?]),+ $(,)?) => {
+        $crate::tensor::Matrix::<_, _, _>::from_array([$([$($x),*]),+])
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::tensor::Vector::<_, _>::from_array([$($x),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tensor_macro() {
+        let v: Vector<i32, 3> = tensor![1, 2, 3];
+        assert_eq!(v.shape(), 3);
+        assert_eq!(v[2], 3);
+
+        let single: Vector<i32, 1> = tensor![7];
+        assert_eq!(single.shape(), 1);
+        assert_eq!(single[0], 7);
+
+        let m: Matrix<i32, 2, 3> = tensor![[1, 2, 3], [4, 5, 6],];
+        assert_eq!(m.shape(), (2, 3));
+        assert_eq!(m[(1, 0)], 4);
+
+        let t: Tensor3<i32, 2, 1, 2> = tensor![[[1, 2]], [[3, 4]]];
+        assert_eq!(t.shape(), (2, 1, 2));
+        assert_eq!(t[(1, 0, 1)], 4);
+    }
+}
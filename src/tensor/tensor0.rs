@@ -1,13 +1,210 @@
-use std::ops::Add;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use crate::{backend::Backend, tensor::Tensor};
+use crate::{
+    backend::Backend,
+    num::{Float, Num, One, Signed, Zero},
+    tensor::{activation, Matrix, Tensor, Tensor3, Vector},
+};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Tensor0<T, B: Backend<T>> {
     pub(crate) repr: B::T0Repr,
     pub(crate) shape: <Tensor0<T, B> as Tensor>::Shape,
 }
 
+impl<T, B: Backend<T>> Tensor0<T, B> {
+    pub fn new(value: T) -> Self {
+        Self {
+            repr: B::t0_from_value(value),
+            shape: (),
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        B::t0_get(&self.repr)
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        B::t0_get_mut(&mut self.repr)
+    }
+
+    pub fn set(&mut self, value: T) {
+        *self.get_mut() = value;
+    }
+}
+
+impl<T, B: Backend<T>> Tensor0<T, B> {
+    pub const fn calculate_reshape(n: usize, d: usize) -> usize {
+        match n {
+            1 => d,
+            _ => panic!("improper reshape"),
+        }
+    }
+
+    pub fn reshape1<const N0: usize>(self) -> Vector<T, { Self::calculate_reshape(N0, N0) }, B> {
+        Vector {
+            repr: B::t1_reshape1(B::t1_from_array([B::t0_into_value(self.repr)])),
+            shape: N0,
+        }
+    }
+
+    pub fn reshape2<const N0: usize, const N1: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reshape(N0 * N1, N0) },
+        { Self::calculate_reshape(N0 * N1, N1) },
+        B,
+    > {
+        Matrix {
+            repr: B::t1_reshape2(B::t1_from_array([B::t0_into_value(self.repr)])),
+            shape: (N0, N1),
+        }
+    }
+
+    pub fn reshape3<const N0: usize, const N1: usize, const N2: usize>(
+        self,
+    ) -> Tensor3<
+        T,
+        { Self::calculate_reshape(N0 * N1 * N2, N0) },
+        { Self::calculate_reshape(N0 * N1 * N2, N1) },
+        { Self::calculate_reshape(N0 * N1 * N2, N2) },
+        B,
+    > {
+        Tensor3 {
+            repr: B::t1_reshape3(B::t1_from_array([B::t0_into_value(self.repr)])),
+            shape: (N0, N1, N2),
+        }
+    }
+
+    pub fn flatten(self) -> Vector<T, 1, B> {
+        Vector {
+            repr: B::t1_reshape1(B::t1_from_array([B::t0_into_value(self.repr)])),
+            shape: 1,
+        }
+    }
+}
+
+impl<T, B: Backend<T>> Tensor0<T, B> {
+    /// Applies `f` to every element. Backends may call `f` from several threads at once.
+    pub fn map(self, f: impl Fn(T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t0_map(self.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with(self, other: Self, f: impl Fn(T, T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t0_zip_with(self.repr, other.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Folds every element into `init`, in row-major order.
+    pub fn fold<U>(self, init: U, f: impl FnMut(U, T) -> U) -> U {
+        B::t0_fold(self.repr, init, f)
+    }
+
+    pub fn exp(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::exp)
+    }
+
+    pub fn ln(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::ln)
+    }
+
+    pub fn sqrt(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sqrt)
+    }
+
+    pub fn abs(self) -> Self
+    where
+        T: Signed,
+    {
+        self.map(T::abs)
+    }
+
+    pub fn sin(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sin)
+    }
+
+    pub fn cos(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::cos)
+    }
+
+    pub fn tanh(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::tanh)
+    }
+
+    /// Raises each element to the integer power `n`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        T: Float,
+    {
+        self.map(|a| a.powi(n))
+    }
+
+    /// Raises each element to the power `n`.
+    pub fn powf(self, n: T) -> Self
+    where
+        T: Float + Sync,
+    {
+        self.map(|a| a.powf(n))
+    }
+
+    /// Applies [`activation::relu`] to each element.
+    pub fn relu(self) -> Self
+    where
+        T: Num,
+    {
+        self.map(activation::relu)
+    }
+
+    /// Applies [`activation::sigmoid`] to each element.
+    pub fn sigmoid(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::sigmoid)
+    }
+
+    /// Applies [`activation::gelu`] to each element.
+    pub fn gelu(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::gelu)
+    }
+
+    /// Applies [`activation::softplus`] to each element.
+    pub fn softplus(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::softplus)
+    }
+}
+
 impl<T, B: Backend<T>> Tensor for Tensor0<T, B> {
     type Shape = ();
     type DataType = T;
@@ -18,7 +215,7 @@ impl<T, B: Backend<T>> Tensor for Tensor0<T, B> {
 
     fn zeros() -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        Self::DataType: Zero + Copy,
     {
         Self {
             repr: B::t0_zero(),
@@ -28,7 +225,7 @@ impl<T, B: Backend<T>> Tensor for Tensor0<T, B> {
 
     fn ones() -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        Self::DataType: One + Copy,
     {
         Self {
             repr: B::t0_one(),
@@ -56,3 +253,89 @@ where
         }
     }
 }
+
+impl<T, B: Backend<T>> Sub for Tensor0<T, B>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            repr: B::t0_t0_sub(self.repr, other.repr),
+            shape: (),
+        }
+    }
+}
+
+impl<T, B: Backend<T>> Mul for Tensor0<T, B>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            repr: B::t0_t0_mul(self.repr, other.repr),
+            shape: (),
+        }
+    }
+}
+
+impl<T, B: Backend<T>> Div for Tensor0<T, B>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            repr: B::t0_t0_div(self.repr, other.repr),
+            shape: (),
+        }
+    }
+}
+
+impl<T, B: Backend<T>> Rem for Tensor0<T, B>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Self {
+            repr: B::t0_t0_rem(self.repr, other.repr),
+            shape: (),
+        }
+    }
+}
+
+impl<T, B: Backend<T>> Neg for Tensor0<T, B>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            repr: B::t0_neg(self.repr),
+            shape: (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::array;
+
+    #[test]
+    fn test_get() {
+        let mut s: Tensor0<u8, array::Backend> = Tensor0::new(4);
+        assert_eq!(*s.get(), 4);
+        *s.get_mut() += 1;
+        assert_eq!(*s.get(), 5);
+        s.set(9);
+        assert_eq!(*s.get(), 9);
+    }
+}
@@ -1,20 +1,82 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
 
 use crate::{
     backend::{AutoSelectBackend, Backend},
-    tensor::{Scalar, Tensor},
+    num::{Float, Num, One, Signed, Zero},
+    tensor::{activation, Matrix, Scalar, Tensor, Tensor3},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Tensor1<T, const D0: usize, B: Backend<T> = AutoSelectBackend> {
-    pub(crate) repr: B::T1Repr,
+    pub(crate) repr: B::T1Repr<D0>,
     pub(crate) shape: <Tensor1<T, D0, B> as Tensor>::Shape,
 }
 
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    pub fn from_array(a: [T; D0]) -> Self {
+        Self {
+            repr: B::t1_from_array(a),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < D0 {
+            Some(B::t1_get(&self.repr, i.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < D0 {
+            Some(B::t1_get_mut(&mut self.repr, i.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, i: usize, value: T) {
+        self[i] = value;
+    }
+
+    /// # Safety
+    ///
+    /// `i` must be less than `D0`.
+    pub unsafe fn get_unchecked(&self, i: usize) -> &T {
+        B::t1_get_unchecked(&self.repr, i.into())
+    }
+
+    /// # Safety
+    ///
+    /// `i` must be less than `D0`.
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T {
+        B::t1_get_unchecked_mut(&mut self.repr, i.into())
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Index<usize> for Tensor1<T, D0, B> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.get(i)
+            .unwrap_or_else(|| panic!("index {i} out of bounds for shape {D0}"))
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> IndexMut<usize> for Tensor1<T, D0, B> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i)
+            .unwrap_or_else(|| panic!("index {i} out of bounds for shape {D0}"))
+    }
+}
+
 impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
     pub fn dot(self, other: Tensor1<T, D0, B>) -> Scalar<T, B>
     where
-        T: From<u8> + Add<Output = T> + Mul<Output = T>,
+        T: Zero + Add<Output = T> + Mul<Output = T>,
     {
         assert_eq!(self.shape, other.shape);
 
@@ -23,6 +85,215 @@ impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
             shape: (),
         }
     }
+
+    pub fn vecmat<const OD1: usize>(self, other: Matrix<T, D0, OD1, B>) -> Tensor1<T, OD1, B>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(self.shape, other.shape.0);
+
+        Tensor1 {
+            repr: B::t1_t2_vecmat(self.repr, other.repr),
+            shape: OD1,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    pub fn sum(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Scalar {
+            repr: B::t1_sum(self.repr),
+            shape: (),
+        }
+    }
+
+    pub fn prod(self) -> Scalar<T, B>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_prod(self.repr),
+            shape: (),
+        }
+    }
+
+    pub fn min(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_min(self.repr),
+            shape: (),
+        }
+    }
+
+    pub fn max(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_max(self.repr),
+            shape: (),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the element count does not fit in `T`, as 256 does not in `u8`.
+    pub fn mean(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_mean(self.repr),
+            shape: (),
+        }
+    }
+
+    pub fn argmin(self) -> Scalar<usize, B>
+    where
+        T: PartialOrd,
+        B: Backend<usize>,
+    {
+        Scalar {
+            repr: <B as Backend<T>>::t1_argmin(self.repr),
+            shape: (),
+        }
+    }
+
+    pub fn argmax(self) -> Scalar<usize, B>
+    where
+        T: PartialOrd,
+        B: Backend<usize>,
+    {
+        Scalar {
+            repr: <B as Backend<T>>::t1_argmax(self.repr),
+            shape: (),
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    /// Applies `f` to every element. Backends may call `f` from several threads at once.
+    pub fn map(self, f: impl Fn(T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t1_map(self.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with(self, other: Self, f: impl Fn(T, T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t1_zip_with(self.repr, other.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Folds every element into `init`, in row-major order.
+    pub fn fold<U>(self, init: U, f: impl FnMut(U, T) -> U) -> U {
+        B::t1_fold(self.repr, init, f)
+    }
+
+    pub fn exp(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::exp)
+    }
+
+    pub fn ln(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::ln)
+    }
+
+    pub fn sqrt(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sqrt)
+    }
+
+    pub fn abs(self) -> Self
+    where
+        T: Signed,
+    {
+        self.map(T::abs)
+    }
+
+    pub fn sin(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sin)
+    }
+
+    pub fn cos(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::cos)
+    }
+
+    pub fn tanh(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::tanh)
+    }
+
+    /// Raises each element to the integer power `n`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        T: Float,
+    {
+        self.map(|a| a.powi(n))
+    }
+
+    /// Raises each element to the power `n`.
+    pub fn powf(self, n: T) -> Self
+    where
+        T: Float + Sync,
+    {
+        self.map(|a| a.powf(n))
+    }
+
+    /// Applies [`activation::relu`] to each element.
+    pub fn relu(self) -> Self
+    where
+        T: Num,
+    {
+        self.map(activation::relu)
+    }
+
+    /// Applies [`activation::sigmoid`] to each element.
+    pub fn sigmoid(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::sigmoid)
+    }
+
+    /// Applies [`activation::gelu`] to each element.
+    pub fn gelu(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::gelu)
+    }
+
+    /// Applies [`activation::softplus`] to each element.
+    pub fn softplus(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::softplus)
+    }
 }
 
 impl<T, const D0: usize, B: Backend<T>> Tensor for Tensor1<T, D0, B> {
@@ -35,20 +306,20 @@ impl<T, const D0: usize, B: Backend<T>> Tensor for Tensor1<T, D0, B> {
 
     fn zeros() -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        Self::DataType: Zero + Copy,
     {
         Self {
-            repr: B::t1_zeros(D0.into()),
+            repr: B::t1_zeros(),
             shape: D0,
         }
     }
 
     fn ones() -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        Self::DataType: One + Copy,
     {
         Self {
-            repr: B::t1_ones(D0.into()),
+            repr: B::t1_ones(),
             shape: D0,
         }
     }
@@ -67,18 +338,102 @@ impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
         }
     }
 
-    pub fn permute<const P0: usize>(
+    pub fn permute<const P0: usize>(self) -> Tensor1<T, D0, B>
+    where
+        [(); Self::calculate_permute(Self::construct_shape(P0), Self::construct_shape(D0), 0)]:,
+    {
+        self
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    pub const fn calculate_reshape((d0,): (usize,), n: usize, d: usize) -> usize {
+        if d0 != n {
+            panic!("improper reshape");
+        }
+        d
+    }
+
+    pub fn reshape1<const N0: usize>(
+        self,
+    ) -> Tensor1<T, { Self::calculate_reshape(Self::construct_shape(D0), N0, N0) }, B> {
+        Tensor1 {
+            repr: B::t1_reshape1(self.repr),
+            shape: N0,
+        }
+    }
+
+    pub fn reshape2<const N0: usize, const N1: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reshape(Self::construct_shape(D0), N0 * N1, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0), N0 * N1, N1) },
+        B,
+    > {
+        Matrix {
+            repr: B::t1_reshape2(self.repr),
+            shape: (N0, N1),
+        }
+    }
+
+    pub fn reshape3<const N0: usize, const N1: usize, const N2: usize>(
         self,
-    ) -> Tensor1<
+    ) -> Tensor3<
         T,
-        { Self::calculate_permute(Self::construct_shape(P0), Self::construct_shape(D0), 0) },
+        { Self::calculate_reshape(Self::construct_shape(D0), N0 * N1 * N2, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0), N0 * N1 * N2, N1) },
+        { Self::calculate_reshape(Self::construct_shape(D0), N0 * N1 * N2, N2) },
         B,
     > {
+        Tensor3 {
+            repr: B::t1_reshape3(self.repr),
+            shape: (N0, N1, N2),
+        }
+    }
+
+    pub fn flatten(self) -> Tensor1<T, D0, B> {
+        self
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Tensor1<T, D0, B> {
+    pub const fn calculate_split((d0,): (usize,), k: usize, part: usize) -> usize {
+        match part {
+            0 if k <= d0 => k,
+            1 if k <= d0 => d0 - k,
+            _ => panic!("improper split"),
+        }
+    }
+
+    pub fn concat<const OD0: usize>(
+        self,
+        other: Tensor1<T, OD0, B>,
+    ) -> Tensor1<T, { D0 + OD0 }, B> {
         Tensor1 {
-            repr: self.repr,
-            shape: Self::calculate_permute(Self::construct_shape(P0), Self::construct_shape(D0), 0),
+            repr: B::t1_concat(self.repr, other.repr),
+            shape: D0 + OD0,
         }
     }
+
+    pub fn split_at<const K: usize>(
+        self,
+    ) -> (
+        Tensor1<T, { Self::calculate_split(Self::construct_shape(D0), K, 0) }, B>,
+        Tensor1<T, { Self::calculate_split(Self::construct_shape(D0), K, 1) }, B>,
+    ) {
+        let (a, b) = B::t1_split(self.repr, K.into());
+        (
+            Tensor1 {
+                repr: a,
+                shape: Self::calculate_split((D0,), K, 0),
+            },
+            Tensor1 {
+                repr: b,
+                shape: Self::calculate_split((D0,), K, 1),
+            },
+        )
+    }
 }
 
 impl<T, const D0: usize, B: Backend<T>> Add<Scalar<T, B>> for Tensor1<T, D0, B>
@@ -108,3 +463,172 @@ where
         }
     }
 }
+
+impl<T, const D0: usize, B: Backend<T>> Sub<Scalar<T, B>> for Tensor1<T, D0, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t1_t0_sub(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Sub for Tensor1<T, D0, B>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            repr: B::t1_t1_sub(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Mul<Scalar<T, B>> for Tensor1<T, D0, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t1_t0_mul(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Mul for Tensor1<T, D0, B>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            repr: B::t1_t1_mul(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Div<Scalar<T, B>> for Tensor1<T, D0, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t1_t0_div(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Div for Tensor1<T, D0, B>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            repr: B::t1_t1_div(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Rem<Scalar<T, B>> for Tensor1<T, D0, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t1_t0_rem(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Rem for Tensor1<T, D0, B>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Self {
+            repr: B::t1_t1_rem(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, B: Backend<T>> Neg for Tensor1<T, D0, B>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            repr: B::t1_neg(self.repr),
+            shape: D0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{array, basic};
+
+    #[test]
+    fn test_get() {
+        let mut v: Tensor1<u8, 3> = Tensor1::from_array([1, 2, 3]);
+        assert_eq!(v.get(2), Some(&3));
+        assert_eq!(v.get(3), None);
+        assert_eq!(unsafe { *v.get_unchecked(1) }, 2);
+
+        v.set(0, 7);
+        v[1] += 1;
+        assert_eq!(v[0], 7);
+        assert_eq!(v[1], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for shape 3")]
+    fn test_index_out_of_bounds() {
+        let v: Tensor1<u8, 3> = Tensor1::from_array([1, 2, 3]);
+        let _ = v[3];
+    }
+
+    #[test]
+    fn test_vecmat() {
+        let v: Tensor1<i32, 2> = Tensor1::from_array([1, 2]);
+        let m: Matrix<i32, 2, 3> = Matrix::from_array([[1, 2, 3], [4, 5, 6]]);
+        let r = v.vecmat(m);
+        assert_eq!((r[0], r[1], r[2]), (9, 12, 15));
+
+        let v: Tensor1<f64, 3, array::Backend> = Tensor1::from_array([1.0, 0.0, -1.0]);
+        let m: Matrix<f64, 3, 2, array::Backend> =
+            Matrix::from_array([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(v.vecmat(m).repr, [-4.0, -4.0]);
+
+        let v: Tensor1<u8, 0, basic::Backend> = Tensor1::from_array([]);
+        let m: Matrix<u8, 0, 2, basic::Backend> = Matrix::from_array([]);
+        assert_eq!(v.vecmat(m).repr, vec![0, 0]);
+    }
+}
@@ -1,23 +1,181 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
 
 use crate::{
-    backend::{AutoSelectBackend, Backend},
-    tensor::{Scalar, Tensor, Vector},
+    backend::{gemm, AutoSelectBackend, Backend},
+    num::{Float, Num, One, Signed, Zero},
+    tensor::{
+        activation,
+        linalg::{self, Lu},
+        Scalar, Tensor, Tensor3, Vector,
+    },
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Tensor2<T, const D0: usize, const D1: usize, B: Backend<T> = AutoSelectBackend> {
-    pub(crate) repr: B::T2Repr,
+    pub(crate) repr: B::T2Repr<D0, D1>,
     pub(crate) shape: <Tensor2<T, D0, D1, B> as Tensor>::Shape,
 }
 
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub fn from_array(a: [[T; D1]; D0]) -> Self {
+        Self {
+            repr: B::t2_from_array(a),
+            shape: (D0, D1),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub fn get(&self, (i, j): (usize, usize)) -> Option<&T> {
+        if i < D0 && j < D1 {
+            Some(B::t2_get(&self.repr, i.into(), j.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (i, j): (usize, usize)) -> Option<&mut T> {
+        if i < D0 && j < D1 {
+            Some(B::t2_get_mut(&mut self.repr, i.into(), j.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, index: (usize, usize), value: T) {
+        self[index] = value;
+    }
+
+    /// # Safety
+    ///
+    /// `i` must be less than `D0` and `j` less than `D1`.
+    pub unsafe fn get_unchecked(&self, (i, j): (usize, usize)) -> &T {
+        B::t2_get_unchecked(&self.repr, i.into(), j.into())
+    }
+
+    /// # Safety
+    ///
+    /// `i` must be less than `D0` and `j` less than `D1`.
+    pub unsafe fn get_unchecked_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        B::t2_get_unchecked_mut(&mut self.repr, i.into(), j.into())
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Index<(usize, usize)>
+    for Tensor2<T, D0, D1, B>
+{
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {index:?} out of bounds for shape ({D0}, {D1})"))
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> IndexMut<(usize, usize)>
+    for Tensor2<T, D0, D1, B>
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {index:?} out of bounds for shape ({D0}, {D1})"))
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    /// Factors the matrix as `QR` with Householder reflections, where `Q` is orthogonal and `R`
+    /// upper triangular with a non-negative diagonal.
+    pub fn qr(self) -> (Tensor2<T, D0, D0, B>, Self)
+    where
+        T: Float,
+    {
+        let (q, r) = linalg::qr(B::t2_into_vec(self.repr), D0, D1);
+        (
+            Tensor2 {
+                repr: B::t2_from_vec(q),
+                shape: (D0, D0),
+            },
+            Self {
+                repr: B::t2_from_vec(r),
+                shape: self.shape,
+            },
+        )
+    }
+
+    /// Returns the `x` minimizing `|self * x - b|`, the least-squares solution of an
+    /// overdetermined system. Matrices with fewer rows than columns do not compile.
+    ///
+    /// Returns `None` if the columns are linearly dependent, up to rounding error, since the
+    /// minimizer is then not unique.
+    pub fn lstsq(self, b: Vector<T, D0, B>) -> Option<Vector<T, D1, B>>
+    where
+        T: Float,
+        [(); D0 - D1]:,
+    {
+        let x = linalg::lstsq(B::t2_into_vec(self.repr), B::t1_into_vec(b.repr), D0, D1)?;
+        Some(Vector {
+            repr: B::t1_from_vec(x),
+            shape: D1,
+        })
+    }
+}
+
 impl<T, const D: usize, B: Backend<T>> Tensor2<T, D, D, B> {
     pub fn identity() -> Self
     where
-        T: From<u8> + Copy,
+        T: Zero + One + Copy,
+    {
+        Self {
+            repr: B::t2_identity(),
+            shape: (D, D),
+        }
+    }
+
+    /// Factors the matrix as `PA = LU` with partial pivoting.
+    pub fn lu(self) -> Lu<T, D, B>
+    where
+        T: Float,
+    {
+        Lu::new(B::t2_into_vec(self.repr))
+    }
+
+    pub fn det(self) -> T
+    where
+        T: Float,
+    {
+        self.lu().det()
+    }
+
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(self) -> Option<Self>
+    where
+        T: Float,
+    {
+        self.lu().inverse()
+    }
+
+    /// Solves `self * x = b` for `x`, or returns `None` if the matrix is singular.
+    ///
+    /// Factor once with [`lu`](Self::lu) to solve against several right-hand sides.
+    pub fn solve(self, b: Vector<T, D, B>) -> Option<Vector<T, D, B>>
+    where
+        T: Float,
+    {
+        self.lu().solve(b)
+    }
+
+    /// Multiplies with Strassen's recursion, falling back to [`gemm::matmul`] once blocks are
+    /// at most [`gemm::STRASSEN_CUTOFF`] wide.
+    ///
+    /// Trades a few multiplications for extra additions and subtractions, so float results
+    /// differ from [`Tensor2::matmul`] by rounding, and unsigned types can overflow where
+    /// `matmul` would not.
+    pub fn matmul_strassen(self, other: Self) -> Self
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero + Copy,
     {
+        let (a, b) = (B::t2_into_vec(self.repr), B::t2_into_vec(other.repr));
         Self {
-            repr: B::t2_identity(D.into()),
+            repr: B::t2_from_vec(gemm::strassen(&a, &b, D, gemm::STRASSEN_CUTOFF)),
             shape: (D, D),
         }
     }
@@ -63,10 +221,10 @@ impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
         B,
     >
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
         Tensor2 {
-            repr: B::t2_transpose(self.repr),
+            repr: B::t2_permute(self.repr, (P0.into(), P1.into())),
             shape: (
                 Self::calculate_permute((P0, P1), (D0, D1), 0),
                 Self::calculate_permute((P0, P1), (D0, D1), 1),
@@ -76,92 +234,1126 @@ impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
 }
 
 impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
-    pub fn matmul<const OD1: usize>(self, other: Tensor2<T, D1, OD1, B>) -> Tensor2<T, D0, OD1, B>
-    where
-        T: Add<Output = T> + Mul<Output = T> + From<u8> + Copy,
-    {
-        assert_eq!(self.shape.1, other.shape.0);
+    pub const fn calculate_reshape((d0, d1): (usize, usize), n: usize, d: usize) -> usize {
+        if d0 * d1 != n {
+            panic!("improper reshape");
+        }
+        d
+    }
+
+    pub fn reshape1<const N0: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reshape(Self::construct_shape(D0, D1), N0, N0) }, B> {
+        Vector {
+            repr: B::t2_reshape1(self.repr),
+            shape: N0,
+        }
+    }
 
+    pub fn reshape2<const N0: usize, const N1: usize>(
+        self,
+    ) -> Tensor2<
+        T,
+        { Self::calculate_reshape(Self::construct_shape(D0, D1), N0 * N1, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1), N0 * N1, N1) },
+        B,
+    > {
         Tensor2 {
-            repr: B::t2_t2_matmul(self.repr, other.repr),
-            shape: (D0, OD1),
+            repr: B::t2_reshape2(self.repr),
+            shape: (N0, N1),
+        }
+    }
+
+    pub fn reshape3<const N0: usize, const N1: usize, const N2: usize>(
+        self,
+    ) -> Tensor3<
+        T,
+        { Self::calculate_reshape(Self::construct_shape(D0, D1), N0 * N1 * N2, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1), N0 * N1 * N2, N1) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1), N0 * N1 * N2, N2) },
+        B,
+    > {
+        Tensor3 {
+            repr: B::t2_reshape3(self.repr),
+            shape: (N0, N1, N2),
+        }
+    }
+
+    pub fn flatten(self) -> Vector<T, { D0 * D1 }, B> {
+        Vector {
+            repr: B::t2_reshape1(self.repr),
+            shape: D0 * D1,
         }
     }
 }
 
-impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor for Tensor2<T, D0, D1, B> {
-    type Shape = (usize, usize);
-    type DataType = T;
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub const fn calculate_concat(
+        a: usize,
+        (d0, d1): (usize, usize),
+        (o0, o1): (usize, usize),
+        i: usize,
+    ) -> usize {
+        let (d, o) = match (a, i) {
+            (0 | 1, 0) => (d0, o0),
+            (0 | 1, 1) => (d1, o1),
+            _ => panic!("improper concat"),
+        };
+        if a == i {
+            d + o
+        } else if d == o {
+            d
+        } else {
+            panic!("improper concat")
+        }
+    }
 
-    fn shape(&self) -> Self::Shape {
-        self.shape
+    pub const fn calculate_split(
+        a: usize,
+        (d0, d1): (usize, usize),
+        k: usize,
+        part: usize,
+        i: usize,
+    ) -> usize {
+        let d = match (a, i) {
+            (0 | 1, 0) => d0,
+            (0 | 1, 1) => d1,
+            _ => panic!("improper split"),
+        };
+        match (a == i, part) {
+            (false, _) => d,
+            (true, 0) if k <= d => k,
+            (true, 1) if k <= d => d - k,
+            _ => panic!("improper split"),
+        }
     }
 
-    fn zeros() -> Self
-    where
-        Self::DataType: From<u8> + Copy,
-    {
+    pub fn stack(rows: [Vector<T, D1, B>; D0]) -> Self {
         Self {
-            repr: B::t2_zeros(D0.into(), D1.into()),
+            repr: B::t2_stack(rows.map(|row| row.repr)),
             shape: (D0, D1),
         }
     }
 
-    fn ones() -> Self
+    pub fn concat<const A: usize, const OD0: usize, const OD1: usize>(
+        self,
+        other: Tensor2<T, OD0, OD1, B>,
+    ) -> Tensor2<
+        T,
+        {
+            Self::calculate_concat(
+                A,
+                Self::construct_shape(D0, D1),
+                Self::construct_shape(OD0, OD1),
+                0,
+            )
+        },
+        {
+            Self::calculate_concat(
+                A,
+                Self::construct_shape(D0, D1),
+                Self::construct_shape(OD0, OD1),
+                1,
+            )
+        },
+        B,
+    > {
+        Tensor2 {
+            repr: B::t2_concat(self.repr, other.repr, A.into()),
+            shape: (
+                Self::calculate_concat(A, (D0, D1), (OD0, OD1), 0),
+                Self::calculate_concat(A, (D0, D1), (OD0, OD1), 1),
+            ),
+        }
+    }
+
+    pub fn split_at<const A: usize, const K: usize>(
+        self,
+    ) -> (
+        Tensor2<
+            T,
+            { Self::calculate_split(A, Self::construct_shape(D0, D1), K, 0, 0) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1), K, 0, 1) },
+            B,
+        >,
+        Tensor2<
+            T,
+            { Self::calculate_split(A, Self::construct_shape(D0, D1), K, 1, 0) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1), K, 1, 1) },
+            B,
+        >,
+    ) {
+        let (a, b) = B::t2_split(self.repr, K.into(), A.into());
+        (
+            Tensor2 {
+                repr: a,
+                shape: (
+                    Self::calculate_split(A, (D0, D1), K, 0, 0),
+                    Self::calculate_split(A, (D0, D1), K, 0, 1),
+                ),
+            },
+            Tensor2 {
+                repr: b,
+                shape: (
+                    Self::calculate_split(A, (D0, D1), K, 1, 0),
+                    Self::calculate_split(A, (D0, D1), K, 1, 1),
+                ),
+            },
+        )
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub const fn calculate_along(a: usize, (d0, d1): (usize, usize)) -> usize {
+        match a {
+            0 => d0,
+            1 => d1,
+            _ => panic!("improper axis"),
+        }
+    }
+
+    pub fn add_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1)) }, B>,
+    ) -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        T: Add<Output = T> + Copy,
     {
         Self {
-            repr: B::t2_ones(D0.into(), D1.into()),
-            shape: (D0, D1),
+            repr: B::t2_t1_add(self.repr, other.repr, A.into()),
+            shape: self.shape,
         }
     }
-}
 
-impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add<Scalar<T, B>> for Tensor2<T, D0, D1, B>
-where
-    T: Add<Output = T> + Copy,
-{
-    type Output = Self;
+    pub fn sub_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1)) }, B>,
+    ) -> Self
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        Self {
+            repr: B::t2_t1_sub(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
 
-    fn add(self, other: Scalar<T, B>) -> Self {
+    pub fn mul_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1)) }, B>,
+    ) -> Self
+    where
+        T: Mul<Output = T> + Copy,
+    {
         Self {
-            repr: B::t2_t0_add(self.repr, other.repr),
+            repr: B::t2_t1_mul(self.repr, other.repr, A.into()),
             shape: self.shape,
         }
     }
-}
 
-/// FIXME: ideally, we'd be able to add a vector to the Tensor2 even if the final dimensions don't match,
-/// but we can't do that right now in a way that maintains the type system's safety. So we rely on folks doing
-/// something like `Tensor2.transpose().add(vector).transpose()` instead.
-impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add<Vector<T, D1, B>>
-    for Tensor2<T, D0, D1, B>
-where
-    T: Add<Output = T> + Copy,
-{
-    type Output = Self;
+    pub fn div_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1)) }, B>,
+    ) -> Self
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self {
+            repr: B::t2_t1_div(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
 
-    fn add(self, other: Vector<T, D1, B>) -> Self {
+    pub fn rem_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1)) }, B>,
+    ) -> Self
+    where
+        T: Rem<Output = T> + Copy,
+    {
         Self {
-            repr: B::t2_t1_add(self.repr, other.repr, 1.into()),
+            repr: B::t2_t1_rem(self.repr, other.repr, A.into()),
             shape: self.shape,
         }
     }
 }
 
-impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add for Tensor2<T, D0, D1, B>
-where
-    T: Add<Output = T>,
-{
-    type Output = Self;
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub fn matmul<const OD1: usize>(self, other: Tensor2<T, D1, OD1, B>) -> Tensor2<T, D0, OD1, B>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(self.shape.1, other.shape.0);
 
-    fn add(self, other: Self) -> Self {
-        assert_eq!(self.shape, other.shape);
+        Tensor2 {
+            repr: B::t2_t2_matmul(self.repr, other.repr),
+            shape: (D0, OD1),
+        }
+    }
 
-        Self {
-            repr: B::t2_t2_add(self.repr, other.repr),
-            shape: self.shape,
+    pub fn matvec(self, other: Vector<T, D1, B>) -> Vector<T, D0, B>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(self.shape.1, other.shape);
+
+        Vector {
+            repr: B::t2_t1_matvec(self.repr, other.repr),
+            shape: D0,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    pub const fn calculate_reduce(a: usize, (d0, d1): (usize, usize)) -> usize {
+        match a {
+            0 => d1,
+            1 => d0,
+            _ => panic!("improper axis"),
+        }
+    }
+
+    pub fn sum_axis<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Vector {
+            repr: B::t2_sum_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn prod_axis<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Vector {
+            repr: B::t2_prod_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn min_axis<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Vector {
+            repr: B::t2_min_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn max_axis<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Vector {
+            repr: B::t2_max_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the length of axis `A` does not fit in `T`, as 256 does not in `u8`.
+    pub fn mean_axis<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Vector {
+            repr: B::t2_mean_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn argmin_axis<const A: usize>(
+        self,
+    ) -> Vector<usize, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: PartialOrd + Copy,
+        B: Backend<usize>,
+    {
+        Vector {
+            repr: <B as Backend<T>>::t2_argmin_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn argmax_axis<const A: usize>(
+        self,
+    ) -> Vector<usize, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: PartialOrd + Copy,
+        B: Backend<usize>,
+    {
+        Vector {
+            repr: <B as Backend<T>>::t2_argmax_axis(self.repr, A.into()),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    pub fn sum(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Scalar {
+            repr: B::t1_sum(B::t2_sum_axis::<D0, D1, D0>(self.repr, 1.into())),
+            shape: (),
         }
     }
+
+    pub fn prod(self) -> Scalar<T, B>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_prod(B::t2_prod_axis::<D0, D1, D0>(self.repr, 1.into())),
+            shape: (),
+        }
+    }
+
+    pub fn min(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_min(B::t2_min_axis::<D0, D1, D0>(self.repr, 1.into())),
+            shape: (),
+        }
+    }
+
+    pub fn max(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_max(B::t2_max_axis::<D0, D1, D0>(self.repr, 1.into())),
+            shape: (),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the element count does not fit in `T`, as 256 does not in `u8`.
+    pub fn mean(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_mean(B::t2_mean_axis::<D0, D1, D0>(self.repr, 1.into())),
+            shape: (),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    /// Applies `f` to every element. Backends may call `f` from several threads at once.
+    pub fn map(self, f: impl Fn(T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t2_map(self.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with(self, other: Self, f: impl Fn(T, T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t2_zip_with(self.repr, other.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Folds every element into `init`, in row-major order.
+    pub fn fold<U>(self, init: U, f: impl FnMut(U, T) -> U) -> U {
+        B::t2_fold(self.repr, init, f)
+    }
+
+    pub fn exp(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::exp)
+    }
+
+    pub fn ln(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::ln)
+    }
+
+    pub fn sqrt(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sqrt)
+    }
+
+    pub fn abs(self) -> Self
+    where
+        T: Signed,
+    {
+        self.map(T::abs)
+    }
+
+    pub fn sin(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sin)
+    }
+
+    pub fn cos(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::cos)
+    }
+
+    pub fn tanh(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::tanh)
+    }
+
+    /// Raises each element to the integer power `n`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        T: Float,
+    {
+        self.map(|a| a.powi(n))
+    }
+
+    /// Raises each element to the power `n`.
+    pub fn powf(self, n: T) -> Self
+    where
+        T: Float + Sync,
+    {
+        self.map(|a| a.powf(n))
+    }
+
+    /// Applies [`activation::relu`] to each element.
+    pub fn relu(self) -> Self
+    where
+        T: Num,
+    {
+        self.map(activation::relu)
+    }
+
+    /// Applies [`activation::sigmoid`] to each element.
+    pub fn sigmoid(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::sigmoid)
+    }
+
+    /// Applies [`activation::gelu`] to each element.
+    pub fn gelu(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::gelu)
+    }
+
+    /// Applies [`activation::softplus`] to each element.
+    pub fn softplus(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::softplus)
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor2<T, D0, D1, B> {
+    /// The maximum along axis `A`, with infinite maxima replaced by zero so that subtracting it
+    /// never produces NaN.
+    fn stable_max<const A: usize>(
+        &self,
+    ) -> B::T1Repr<{ Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }>
+    where
+        T: Float,
+    {
+        let max = B::t2_max_axis(self.repr.clone(), A.into());
+        B::t1_map(max, |m| if m.is_finite() { m } else { T::zero() })
+    }
+
+    /// `ln(sum(exp(x)))` along axis `A`, shifted by the maximum so that large inputs do not
+    /// overflow.
+    pub fn logsumexp<const A: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) }, B>
+    where
+        T: Float,
+    {
+        let max = self.stable_max::<A>();
+        let shifted = B::t2_t1_sub(self.repr, max.clone(), (1 - A).into());
+        let sum = B::t2_sum_axis::<
+            D0,
+            D1,
+            { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) },
+        >(B::t2_map(shifted, T::exp), A.into());
+        Vector {
+            repr: B::t1_t1_add(B::t1_map(sum, T::ln), max),
+            shape: Self::calculate_reduce(A, (D0, D1)),
+        }
+    }
+
+    /// Normalizes `exp(x)` to sum to one along axis `A`, so `softmax::<1>()` turns each row of
+    /// logits into probabilities.
+    pub fn softmax<const A: usize>(self) -> Self
+    where
+        T: Float,
+        [(); Self::calculate_reduce(A, Self::construct_shape(D0, D1))]:,
+    {
+        let max = self.stable_max::<A>();
+        let exp = B::t2_map(B::t2_t1_sub(self.repr, max, (1 - A).into()), T::exp);
+        let sum = B::t2_sum_axis::<
+            D0,
+            D1,
+            { Self::calculate_reduce(A, Self::construct_shape(D0, D1)) },
+        >(exp.clone(), A.into());
+        Self {
+            repr: B::t2_t1_div(exp, sum, (1 - A).into()),
+            shape: self.shape,
+        }
+    }
+
+    /// The logarithm of [`softmax`](Self::softmax), computed as `x - logsumexp(x)`.
+    pub fn log_softmax<const A: usize>(self) -> Self
+    where
+        T: Float,
+        [(); Self::calculate_reduce(A, Self::construct_shape(D0, D1))]:,
+    {
+        let lse = Self {
+            repr: self.repr.clone(),
+            shape: self.shape,
+        }
+        .logsumexp::<A>();
+        Self {
+            repr: B::t2_t1_sub(self.repr, lse.repr, (1 - A).into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Tensor for Tensor2<T, D0, D1, B> {
+    type Shape = (usize, usize);
+    type DataType = T;
+
+    fn shape(&self) -> Self::Shape {
+        self.shape
+    }
+
+    fn zeros() -> Self
+    where
+        Self::DataType: Zero + Copy,
+    {
+        Self {
+            repr: B::t2_zeros(),
+            shape: (D0, D1),
+        }
+    }
+
+    fn ones() -> Self
+    where
+        Self::DataType: One + Copy,
+    {
+        Self {
+            repr: B::t2_ones(),
+            shape: (D0, D1),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add<Scalar<T, B>> for Tensor2<T, D0, D1, B>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t2_t0_add(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+/// Adds the vector to every row. Use [`Tensor2::add_along`] with `A = 0` to add a column vector instead.
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add<Vector<T, D1, B>>
+    for Tensor2<T, D0, D1, B>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, other: Vector<T, D1, B>) -> Self {
+        Self {
+            repr: B::t2_t1_add(self.repr, other.repr, 1.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Add for Tensor2<T, D0, D1, B>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t2_t2_add(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Sub<Scalar<T, B>> for Tensor2<T, D0, D1, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t2_t0_sub(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Sub<Vector<T, D1, B>>
+    for Tensor2<T, D0, D1, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Vector<T, D1, B>) -> Self {
+        Self {
+            repr: B::t2_t1_sub(self.repr, other.repr, 1.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Sub for Tensor2<T, D0, D1, B>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t2_t2_sub(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Mul<Scalar<T, B>> for Tensor2<T, D0, D1, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t2_t0_mul(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Mul<Vector<T, D1, B>>
+    for Tensor2<T, D0, D1, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Vector<T, D1, B>) -> Self {
+        Self {
+            repr: B::t2_t1_mul(self.repr, other.repr, 1.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Mul for Tensor2<T, D0, D1, B>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t2_t2_mul(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Div<Scalar<T, B>> for Tensor2<T, D0, D1, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t2_t0_div(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Div<Vector<T, D1, B>>
+    for Tensor2<T, D0, D1, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Vector<T, D1, B>) -> Self {
+        Self {
+            repr: B::t2_t1_div(self.repr, other.repr, 1.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Div for Tensor2<T, D0, D1, B>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t2_t2_div(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Rem<Scalar<T, B>> for Tensor2<T, D0, D1, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t2_t0_rem(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Rem<Vector<T, D1, B>>
+    for Tensor2<T, D0, D1, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Vector<T, D1, B>) -> Self {
+        Self {
+            repr: B::t2_t1_rem(self.repr, other.repr, 1.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Rem for Tensor2<T, D0, D1, B>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t2_t2_rem(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, B: Backend<T>> Neg for Tensor2<T, D0, D1, B>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            repr: B::t2_neg(self.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{array, basic, flat, threaded};
+
+    #[test]
+    fn test_get() {
+        let mut m: Tensor2<u8, 2, 3> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.get((1, 2)), Some(&6));
+        assert_eq!(m.get((0, 3)), None);
+        assert_eq!(m.get((2, 0)), None);
+
+        m.set((0, 1), 7);
+        m[(1, 0)] += 1;
+        assert_eq!(m[(0, 1)], 7);
+        assert_eq!(m[(1, 0)], 5);
+    }
+
+    #[test]
+    fn test_elementwise_ops() {
+        let a: Tensor2<i32, 2, 2> = Tensor2::from_array([[1, 2], [3, 4]]);
+        let b: Tensor2<i32, 2, 2> = Tensor2::from_array([[4, 3], [2, 1]]);
+        let c = -(a - b) * Scalar::new(2) + Vector::from_array([1, 0]);
+        assert_eq!(c[(0, 0)], 7);
+        assert_eq!(c[(0, 1)], 2);
+        assert_eq!(c[(1, 0)], -1);
+        assert_eq!(c[(1, 1)], -6);
+    }
+
+    #[test]
+    fn test_add_along() {
+        let a: Tensor2<i32, 2, 3> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        let rows = a.clone().add_along::<0>(Vector::from_array([10, 20]));
+        let cols = a.add_along::<1>(Vector::from_array([10, 20, 30]));
+        assert_eq!(rows[(0, 2)], 13);
+        assert_eq!(rows[(1, 0)], 24);
+        assert_eq!(cols[(0, 2)], 33);
+        assert_eq!(cols[(1, 0)], 14);
+    }
+
+    #[test]
+    fn test_reductions() {
+        let logits: Tensor2<f32, 2, 3> = Tensor2::from_array([[0.1, 0.7, 0.2], [0.5, 0.3, 0.2]]);
+        let predictions = logits.clone().argmax_axis::<1>();
+        assert_eq!(predictions.shape(), 2);
+        assert_eq!(predictions[0], 1);
+        assert_eq!(predictions[1], 0);
+
+        let sums: Vector<f32, 3> = logits.clone().sum_axis::<0>();
+        assert!((sums[1] - 1.0).abs() < 1e-6);
+        assert!((*logits.sum().get() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_map_zip_with_fold() {
+        let a: Tensor2<i32, 2, 3> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        let b: Tensor2<i32, 2, 3> = Tensor2::from_array([[6, 5, 4], [3, 2, 1]]);
+        let c = a.clone().map(|x| x * x).zip_with(b, |x, y| x - y);
+        assert_eq!(c[(0, 0)], -5);
+        assert_eq!(c[(1, 2)], 35);
+
+        let digits = a.clone().fold(0, |acc, x| acc * 10 + x);
+        assert_eq!(digits, 123456);
+        let transposed = a.permute::<1, 0>().fold(Vec::new(), |mut acc, x| {
+            acc.push(x);
+            acc
+        });
+        assert_eq!(transposed, vec![1, 4, 2, 5, 3, 6]);
+
+        let t: Tensor3<u8, 2, 1, 2, basic::Backend> = Tensor3::from_array([[[1, 2]], [[3, 4]]]);
+        let max = t.clone().zip_with(t.map(|x| 5 - x), u8::max);
+        assert_eq!(max.fold(0, |acc, x| acc + u32::from(x)), 14);
+    }
+
+    #[test]
+    fn test_unary_math() {
+        let a: Tensor2<f64, 2, 2> = Tensor2::from_array([[1.0, 4.0], [0.25, 9.0]]);
+        let b = a.clone().sqrt();
+        assert_eq!(b[(0, 1)], 2.0);
+        assert_eq!(b[(1, 0)], 0.5);
+        let c = a.clone().ln().exp();
+        assert!((c[(1, 1)] - 9.0).abs() < 1e-12);
+        assert_eq!(a.clone().powi(2)[(0, 1)], 16.0);
+        assert_eq!(a.powf(0.5)[(1, 1)], 3.0);
+
+        let t: Tensor2<f32, 1, 2, basic::Backend> = Tensor2::from_array([[0.0, 1.0]]);
+        assert_eq!(t.clone().sin()[(0, 0)], 0.0);
+        assert_eq!(t.clone().cos()[(0, 0)], 1.0);
+        assert_eq!(t.tanh()[(0, 1)], 1.0f32.tanh());
+
+        let i: Tensor2<i32, 1, 3, array::Backend> = Tensor2::from_array([[-2, 0, 3]]);
+        assert_eq!(i.abs().repr, [[2, 0, 3]]);
+        assert_eq!(i.relu().repr, [[0, 0, 3]]);
+    }
+
+    #[test]
+    fn test_activations() {
+        let x: Tensor2<f64, 2, 2, threaded::Backend<2, 0>> =
+            Tensor2::from_array([[-1.0, 0.0], [1.0, 20.0]]);
+        let s = x.clone().sigmoid();
+        assert_eq!(s[(0, 1)], 0.5);
+        assert!((s[(0, 0)] + s[(1, 0)] - 1.0).abs() < 1e-12);
+        let g = x.clone().gelu();
+        assert_eq!(g[(0, 1)], 0.0);
+        assert!((g[(1, 0)] - 0.8411919906082768).abs() < 1e-12);
+        let p = x.softplus();
+        assert!((p[(0, 1)] - 2.0f64.ln()).abs() < 1e-12);
+        assert!((p[(1, 1)] - 20.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_softmax() {
+        let logits: Tensor2<f64, 2, 3> =
+            Tensor2::from_array([[1000.0, 1001.0, 1002.0], [0.0, 0.0, 0.0]]);
+        let expected = [0.09003057317038046, 0.24472847105479767, 0.6652409557748219];
+
+        let rows = logits.clone().softmax::<1>();
+        for (j, &e) in expected.iter().enumerate() {
+            assert!((rows[(0, j)] - e).abs() < 1e-12);
+            assert!((rows[(1, j)] - 1.0 / 3.0).abs() < 1e-12);
+        }
+
+        let cols = logits.clone().softmax::<0>();
+        assert_eq!(cols[(0, 0)], 1.0);
+        assert_eq!(cols[(1, 2)], 0.0);
+
+        let log = logits.clone().log_softmax::<1>();
+        assert!((log[(0, 2)] - expected[2].ln()).abs() < 1e-12);
+        assert!((log[(1, 0)] + 3.0f64.ln()).abs() < 1e-12);
+
+        let lse = logits.logsumexp::<1>();
+        assert!((lse[0] - 1002.4076059644444).abs() < 1e-9);
+        assert!((lse[1] - 3.0f64.ln()).abs() < 1e-12);
+
+        let masked: Tensor2<f32, 1, 2, array::Backend> =
+            Tensor2::from_array([[f32::NEG_INFINITY, 1.0]]);
+        assert_eq!(masked.softmax::<1>().repr, [[0.0, 1.0]]);
+        let all_masked: Tensor2<f32, 1, 2, basic::Backend> =
+            Tensor2::from_array([[f32::NEG_INFINITY; 2]]);
+        assert_eq!(
+            *all_masked.logsumexp::<1>().get(0).unwrap(),
+            f32::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_reshape() {
+        let a: Tensor2<i32, 2, 3> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        let b: Tensor2<i32, 3, 2> = a.clone().reshape2::<3, 2>();
+        let c: Tensor3<i32, 1, 2, 3> = b.clone().reshape3::<1, 2, 3>();
+        let v: Vector<i32, 6> = a.flatten();
+        assert_eq!(b[(2, 0)], 5);
+        assert_eq!(c[(0, 1, 0)], 4);
+        assert_eq!(v[5], 6);
+        assert_eq!(c.flatten().reshape1::<6>()[3], 4);
+    }
+
+    #[test]
+    fn test_concat_and_split() {
+        let a: Tensor2<i32, 2, 2> = Tensor2::from_array([[1, 2], [3, 4]]);
+        let b: Tensor2<i32, 1, 2> = Tensor2::from_array([[5, 6]]);
+        let c: Tensor2<i32, 3, 2> = a.clone().concat::<0, _, _>(b);
+        assert_eq!(c[(2, 1)], 6);
+
+        let (top, bottom) = c.split_at::<0, 2>();
+        assert_eq!(top.shape(), (2, 2));
+        assert_eq!(bottom[(0, 0)], 5);
+
+        let (left, right): (Tensor2<i32, 2, 1>, Tensor2<i32, 2, 1>) = top.split_at::<1, 1>();
+        assert_eq!(left[(1, 0)], 3);
+        assert_eq!(right[(1, 0)], 4);
+
+        let batch: Tensor3<i32, 2, 2, 2> = Tensor3::stack([a.clone(), a]);
+        assert_eq!(batch[(1, 1, 0)], 3);
+    }
+
+    #[test]
+    fn test_permute() {
+        let a: Tensor2<i32, 2, 2> = Tensor2::from_array([[1, 2], [3, 4]]);
+        assert_eq!(a.clone().permute::<0, 1>()[(0, 1)], 2);
+        assert_eq!(a.permute::<1, 0>()[(0, 1)], 3);
+
+        let b: Tensor2<i32, 2, 3, flat::Backend> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        let t = b.clone().permute::<1, 0>();
+        assert!(!t.repr.is_contiguous());
+        assert_eq!(t[(2, 0)], 3);
+        assert!(b.permute::<0, 1>().repr.is_contiguous());
+    }
+
+    fn square<T, B: Backend<T>>(f: impl Fn(usize) -> T) -> Tensor2<T, 75, 75, B> {
+        Tensor2 {
+            repr: B::t2_from_vec((0..75 * 75).map(f).collect()),
+            shape: (75, 75),
+        }
+    }
+
+    #[test]
+    fn test_matmul_strassen() {
+        let a: Tensor2<i64, 75, 75> = square(|i| (i % 11) as i64 - 5);
+        let b: Tensor2<i64, 75, 75> = square(|i| (i % 7) as i64 - 3);
+        let expected = a.clone().matmul(b.clone());
+        assert_eq!(a.matmul_strassen(b).repr, expected.repr);
+
+        let a: Tensor2<f64, 75, 75, basic::Backend> = square(|i| (i as f64 * 0.37).sin());
+        let b: Tensor2<f64, 75, 75, basic::Backend> = square(|i| (i as f64 * 0.11).cos());
+        let expected = a.clone().matmul(b.clone());
+        let result = a.matmul_strassen(b);
+        for i in 0..75 {
+            for j in 0..75 {
+                assert!((result[(i, j)] - expected[(i, j)]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_backend() {
+        let a: Tensor2<i32, 2, 2, array::Backend> = Tensor2::from_array([[1, 2], [3, 4]]);
+        let b = a;
+        let c = a.matmul(b) + a.permute::<1, 0>();
+        assert_eq!(c.repr, [[8, 13], [17, 26]]);
+        assert_eq!(a.sum_axis::<0>().repr, [4, 6]);
+    }
+
+    #[test]
+    fn test_threaded_backend() {
+        type B = threaded::Backend<2, 0>;
+        let a: Tensor2<i32, 2, 3, B> = Tensor2::from_array([[1, 2, 3], [4, 5, 6]]);
+        let c =
+            a.clone().matmul(a.clone().permute::<1, 0>()) + a.clone().matmul(a.permute::<1, 0>());
+        assert_eq!(c[(0, 1)], 64);
+        assert_eq!(c.sum().repr, 310);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) out of bounds for shape (2, 3)")]
+    fn test_index_out_of_bounds() {
+        let m: Tensor2<u8, 2, 3> = Tensor2::zeros();
+        let _ = m[(0, 3)];
+    }
 }
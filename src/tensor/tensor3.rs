@@ -1,11 +1,12 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
 
 use crate::{
     backend::{AutoSelectBackend, Backend},
-    tensor::{Scalar, Tensor, Vector},
+    num::{Float, Num, One, Signed, Zero},
+    tensor::{activation, Matrix, Scalar, Tensor, Vector},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Tensor3<
     T,
     const D0: usize,
@@ -13,10 +14,79 @@ pub struct Tensor3<
     const D2: usize,
     B: Backend<T> = AutoSelectBackend,
 > {
-    pub(crate) repr: B::T3Repr,
+    pub(crate) repr: B::T3Repr<D0, D1, D2>,
     pub(crate) shape: <Tensor3<T, D0, D1, D2, B> as Tensor>::Shape,
 }
 
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub fn from_array(a: [[[T; D2]; D1]; D0]) -> Self {
+        Self {
+            repr: B::t3_from_array(a),
+            shape: (D0, D1, D2),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub fn get(&self, (i, j, k): (usize, usize, usize)) -> Option<&T> {
+        if i < D0 && j < D1 && k < D2 {
+            Some(B::t3_get(&self.repr, i.into(), j.into(), k.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (i, j, k): (usize, usize, usize)) -> Option<&mut T> {
+        if i < D0 && j < D1 && k < D2 {
+            Some(B::t3_get_mut(&mut self.repr, i.into(), j.into(), k.into()))
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, index: (usize, usize, usize), value: T) {
+        self[index] = value;
+    }
+
+    /// # Safety
+    ///
+    /// `i`, `j` and `k` must be less than `D0`, `D1` and `D2` respectively.
+    pub unsafe fn get_unchecked(&self, (i, j, k): (usize, usize, usize)) -> &T {
+        B::t3_get_unchecked(&self.repr, i.into(), j.into(), k.into())
+    }
+
+    /// # Safety
+    ///
+    /// `i`, `j` and `k` must be less than `D0`, `D1` and `D2` respectively.
+    pub unsafe fn get_unchecked_mut(&mut self, (i, j, k): (usize, usize, usize)) -> &mut T {
+        B::t3_get_unchecked_mut(&mut self.repr, i.into(), j.into(), k.into())
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Index<(usize, usize, usize)> for Tensor3<T, D0, D1, D2, B>
+{
+    type Output = T;
+
+    fn index(&self, index: (usize, usize, usize)) -> &T {
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {index:?} out of bounds for shape ({D0}, {D1}, {D2})"))
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    IndexMut<(usize, usize, usize)> for Tensor3<T, D0, D1, D2, B>
+{
+    fn index_mut(&mut self, index: (usize, usize, usize)) -> &mut T {
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {index:?} out of bounds for shape ({D0}, {D1}, {D2})"))
+    }
+}
+
 impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
     Tensor3<T, D0, D1, D2, B>
 {
@@ -80,7 +150,7 @@ impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
         B,
     >
     where
-        T: From<u8> + Copy,
+        T: Zero + Copy,
     {
         Tensor3 {
             repr: B::t3_permute(self.repr, (P0.into(), P1.into(), P2.into())),
@@ -93,33 +163,1246 @@ impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
     }
 }
 
-impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Tensor
-    for Tensor3<T, D0, D1, D2, B>
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
 {
-    type Shape = (usize, usize, usize);
-    type DataType = T;
+    pub const fn calculate_reshape(
+        (d0, d1, d2): (usize, usize, usize),
+        n: usize,
+        d: usize,
+    ) -> usize {
+        if d0 * d1 * d2 != n {
+            panic!("improper reshape");
+        }
+        d
+    }
 
-    fn shape(&self) -> Self::Shape {
-        self.shape
+    pub fn reshape1<const N0: usize>(
+        self,
+    ) -> Vector<T, { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0, N0) }, B> {
+        Vector {
+            repr: B::t3_reshape1(self.repr),
+            shape: N0,
+        }
     }
 
-    fn zeros() -> Self
+    pub fn reshape2<const N0: usize, const N1: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0 * N1, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0 * N1, N1) },
+        B,
+    > {
+        Matrix {
+            repr: B::t3_reshape2(self.repr),
+            shape: (N0, N1),
+        }
+    }
+
+    pub fn reshape3<const N0: usize, const N1: usize, const N2: usize>(
+        self,
+    ) -> Tensor3<
+        T,
+        { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0 * N1 * N2, N0) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0 * N1 * N2, N1) },
+        { Self::calculate_reshape(Self::construct_shape(D0, D1, D2), N0 * N1 * N2, N2) },
+        B,
+    > {
+        Tensor3 {
+            repr: B::t3_reshape3(self.repr),
+            shape: (N0, N1, N2),
+        }
+    }
+
+    pub fn flatten(self) -> Vector<T, { D0 * D1 * D2 }, B> {
+        Vector {
+            repr: B::t3_reshape1(self.repr),
+            shape: D0 * D1 * D2,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub const fn calculate_concat(
+        a: usize,
+        (d0, d1, d2): (usize, usize, usize),
+        (o0, o1, o2): (usize, usize, usize),
+        i: usize,
+    ) -> usize {
+        let (d, o) = match (a, i) {
+            (0..=2, 0) => (d0, o0),
+            (0..=2, 1) => (d1, o1),
+            (0..=2, 2) => (d2, o2),
+            _ => panic!("improper concat"),
+        };
+        if a == i {
+            d + o
+        } else if d == o {
+            d
+        } else {
+            panic!("improper concat")
+        }
+    }
+
+    pub const fn calculate_split(
+        a: usize,
+        (d0, d1, d2): (usize, usize, usize),
+        k: usize,
+        part: usize,
+        i: usize,
+    ) -> usize {
+        let d = match (a, i) {
+            (0..=2, 0) => d0,
+            (0..=2, 1) => d1,
+            (0..=2, 2) => d2,
+            _ => panic!("improper split"),
+        };
+        match (a == i, part) {
+            (false, _) => d,
+            (true, 0) if k <= d => k,
+            (true, 1) if k <= d => d - k,
+            _ => panic!("improper split"),
+        }
+    }
+
+    pub fn stack(matrices: [Matrix<T, D1, D2, B>; D0]) -> Self {
+        Self {
+            repr: B::t3_stack(matrices.map(|matrix| matrix.repr)),
+            shape: (D0, D1, D2),
+        }
+    }
+
+    pub fn concat<const A: usize, const OD0: usize, const OD1: usize, const OD2: usize>(
+        self,
+        other: Tensor3<T, OD0, OD1, OD2, B>,
+    ) -> Tensor3<
+        T,
+        {
+            Self::calculate_concat(
+                A,
+                Self::construct_shape(D0, D1, D2),
+                Self::construct_shape(OD0, OD1, OD2),
+                0,
+            )
+        },
+        {
+            Self::calculate_concat(
+                A,
+                Self::construct_shape(D0, D1, D2),
+                Self::construct_shape(OD0, OD1, OD2),
+                1,
+            )
+        },
+        {
+            Self::calculate_concat(
+                A,
+                Self::construct_shape(D0, D1, D2),
+                Self::construct_shape(OD0, OD1, OD2),
+                2,
+            )
+        },
+        B,
+    > {
+        Tensor3 {
+            repr: B::t3_concat(self.repr, other.repr, A.into()),
+            shape: (
+                Self::calculate_concat(A, (D0, D1, D2), (OD0, OD1, OD2), 0),
+                Self::calculate_concat(A, (D0, D1, D2), (OD0, OD1, OD2), 1),
+                Self::calculate_concat(A, (D0, D1, D2), (OD0, OD1, OD2), 2),
+            ),
+        }
+    }
+
+    pub fn split_at<const A: usize, const K: usize>(
+        self,
+    ) -> (
+        Tensor3<
+            T,
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 0, 0) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 0, 1) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 0, 2) },
+            B,
+        >,
+        Tensor3<
+            T,
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 1, 0) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 1, 1) },
+            { Self::calculate_split(A, Self::construct_shape(D0, D1, D2), K, 1, 2) },
+            B,
+        >,
+    ) {
+        let (a, b) = B::t3_split(self.repr, K.into(), A.into());
+        (
+            Tensor3 {
+                repr: a,
+                shape: (
+                    Self::calculate_split(A, (D0, D1, D2), K, 0, 0),
+                    Self::calculate_split(A, (D0, D1, D2), K, 0, 1),
+                    Self::calculate_split(A, (D0, D1, D2), K, 0, 2),
+                ),
+            },
+            Tensor3 {
+                repr: b,
+                shape: (
+                    Self::calculate_split(A, (D0, D1, D2), K, 1, 0),
+                    Self::calculate_split(A, (D0, D1, D2), K, 1, 1),
+                    Self::calculate_split(A, (D0, D1, D2), K, 1, 2),
+                ),
+            },
+        )
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub const fn calculate_along(a: usize, (d0, d1, d2): (usize, usize, usize)) -> usize {
+        match a {
+            0 => d0,
+            1 => d1,
+            2 => d2,
+            _ => panic!("improper axis"),
+        }
+    }
+
+    pub fn add_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1, D2)) }, B>,
+    ) -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        T: Add<Output = T> + Copy,
     {
         Self {
-            repr: B::t3_zeros(D0.into(), D1.into(), D2.into()),
-            shape: (D0, D1, D2),
+            repr: B::t3_t1_add(self.repr, other.repr, A.into()),
+            shape: self.shape,
         }
     }
 
-    fn ones() -> Self
+    pub fn sub_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1, D2)) }, B>,
+    ) -> Self
     where
-        Self::DataType: From<u8> + Copy,
+        T: Sub<Output = T> + Copy,
     {
         Self {
-            repr: B::t3_ones(D0.into(), D1.into(), D2.into()),
-            shape: (D0, D1, D2),
+            repr: B::t3_t1_sub(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
+
+    pub fn mul_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1, D2)) }, B>,
+    ) -> Self
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        Self {
+            repr: B::t3_t1_mul(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
+
+    pub fn div_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1, D2)) }, B>,
+    ) -> Self
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Self {
+            repr: B::t3_t1_div(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
+
+    pub fn rem_along<const A: usize>(
+        self,
+        other: Vector<T, { Self::calculate_along(A, Self::construct_shape(D0, D1, D2)) }, B>,
+    ) -> Self
+    where
+        T: Rem<Output = T> + Copy,
+    {
+        Self {
+            repr: B::t3_t1_rem(self.repr, other.repr, A.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub const fn calculate_reduce(
+        a: usize,
+        (d0, d1, d2): (usize, usize, usize),
+        i: usize,
+    ) -> usize {
+        match (a, i) {
+            (0, 0) => d1,
+            (0, 1) => d2,
+            (1, 0) => d0,
+            (1, 1) => d2,
+            (2, 0) => d0,
+            (2, 1) => d1,
+            _ => panic!("improper axis"),
+        }
+    }
+
+    pub fn sum_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Matrix {
+            repr: B::t3_sum_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn prod_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Matrix {
+            repr: B::t3_prod_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn min_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: PartialOrd + Copy,
+    {
+        Matrix {
+            repr: B::t3_min_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn max_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: PartialOrd + Copy,
+    {
+        Matrix {
+            repr: B::t3_max_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the length of axis `A` does not fit in `T`, as 256 does not in `u8`.
+    pub fn mean_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        T,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Matrix {
+            repr: B::t3_mean_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn argmin_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        usize,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: PartialOrd + Copy,
+        B: Backend<usize>,
+    {
+        Matrix {
+            repr: <B as Backend<T>>::t3_argmin_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn argmax_axis<const A: usize>(
+        self,
+    ) -> Matrix<
+        usize,
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 0) },
+        { Self::calculate_reduce(A, Self::construct_shape(D0, D1, D2), 1) },
+        B,
+    >
+    where
+        T: PartialOrd + Copy,
+        B: Backend<usize>,
+    {
+        Matrix {
+            repr: <B as Backend<T>>::t3_argmax_axis(self.repr, A.into()),
+            shape: (
+                Self::calculate_reduce(A, (D0, D1, D2), 0),
+                Self::calculate_reduce(A, (D0, D1, D2), 1),
+            ),
+        }
+    }
+
+    pub fn sum(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Zero + Copy,
+    {
+        Scalar {
+            repr: B::t1_sum(B::t2_sum_axis::<D0, D1, D0>(
+                B::t3_sum_axis::<D0, D1, D2, D0, D1>(self.repr, 2.into()),
+                1.into(),
+            )),
+            shape: (),
         }
     }
+
+    pub fn prod(self) -> Scalar<T, B>
+    where
+        T: Mul<Output = T> + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_prod(B::t2_prod_axis::<D0, D1, D0>(
+                B::t3_prod_axis::<D0, D1, D2, D0, D1>(self.repr, 2.into()),
+                1.into(),
+            )),
+            shape: (),
+        }
+    }
+
+    pub fn min(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_min(B::t2_min_axis::<D0, D1, D0>(
+                B::t3_min_axis::<D0, D1, D2, D0, D1>(self.repr, 2.into()),
+                1.into(),
+            )),
+            shape: (),
+        }
+    }
+
+    pub fn max(self) -> Scalar<T, B>
+    where
+        T: PartialOrd + Copy,
+    {
+        Scalar {
+            repr: B::t1_max(B::t2_max_axis::<D0, D1, D0>(
+                B::t3_max_axis::<D0, D1, D2, D0, D1>(self.repr, 2.into()),
+                1.into(),
+            )),
+            shape: (),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the element count does not fit in `T`, as 256 does not in `u8`.
+    pub fn mean(self) -> Scalar<T, B>
+    where
+        T: Add<Output = T> + Div<Output = T> + Zero + One + Copy,
+    {
+        Scalar {
+            repr: B::t1_mean(B::t2_mean_axis::<D0, D1, D0>(
+                B::t3_mean_axis::<D0, D1, D2, D0, D1>(self.repr, 2.into()),
+                1.into(),
+            )),
+            shape: (),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    pub fn bmm<const OD2: usize>(
+        self,
+        other: Tensor3<T, D0, D2, OD2, B>,
+    ) -> Tensor3<T, D0, D1, OD2, B>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(self.shape.0, other.shape.0);
+        assert_eq!(self.shape.2, other.shape.1);
+
+        Tensor3 {
+            repr: B::t3_t3_bmm(self.repr, other.repr),
+            shape: (D0, D1, OD2),
+        }
+    }
+
+    pub fn matmul<const OD1: usize>(
+        self,
+        other: Matrix<T, D2, OD1, B>,
+    ) -> Tensor3<T, D0, D1, OD1, B>
+    where
+        T: Add<Output = T> + Mul<Output = T> + Zero + Copy,
+    {
+        assert_eq!(self.shape.2, other.shape.0);
+
+        Tensor3 {
+            repr: B::t3_t2_matmul(self.repr, other.repr),
+            shape: (D0, D1, OD1),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    /// Applies `f` to every element. Backends may call `f` from several threads at once.
+    pub fn map(self, f: impl Fn(T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t3_map(self.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with(self, other: Self, f: impl Fn(T, T) -> T + Sync) -> Self {
+        Self {
+            repr: B::t3_zip_with(self.repr, other.repr, f),
+            shape: self.shape,
+        }
+    }
+
+    /// Folds every element into `init`, in row-major order.
+    pub fn fold<U>(self, init: U, f: impl FnMut(U, T) -> U) -> U {
+        B::t3_fold(self.repr, init, f)
+    }
+
+    pub fn exp(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::exp)
+    }
+
+    pub fn ln(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::ln)
+    }
+
+    pub fn sqrt(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sqrt)
+    }
+
+    pub fn abs(self) -> Self
+    where
+        T: Signed,
+    {
+        self.map(T::abs)
+    }
+
+    pub fn sin(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::sin)
+    }
+
+    pub fn cos(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::cos)
+    }
+
+    pub fn tanh(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(T::tanh)
+    }
+
+    /// Raises each element to the integer power `n`.
+    pub fn powi(self, n: i32) -> Self
+    where
+        T: Float,
+    {
+        self.map(|a| a.powi(n))
+    }
+
+    /// Raises each element to the power `n`.
+    pub fn powf(self, n: T) -> Self
+    where
+        T: Float + Sync,
+    {
+        self.map(|a| a.powf(n))
+    }
+
+    /// Applies [`activation::relu`] to each element.
+    pub fn relu(self) -> Self
+    where
+        T: Num,
+    {
+        self.map(activation::relu)
+    }
+
+    /// Applies [`activation::sigmoid`] to each element.
+    pub fn sigmoid(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::sigmoid)
+    }
+
+    /// Applies [`activation::gelu`] to each element.
+    pub fn gelu(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::gelu)
+    }
+
+    /// Applies [`activation::softplus`] to each element.
+    pub fn softplus(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(activation::softplus)
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>>
+    Tensor3<T, D0, D1, D2, B>
+{
+    /// Swaps the first and last axes, so that the last axis can be reduced and broadcast back
+    /// over like axis 0. The swap is its own inverse.
+    fn swap_last<const S0: usize, const S1: usize, const S2: usize>(
+        a: B::T3Repr<S0, S1, S2>,
+    ) -> B::T3Repr<S2, S1, S0>
+    where
+        T: Float,
+    {
+        B::t3_permute(a, (2.into(), 1.into(), 0.into()))
+    }
+
+    /// The maximum over axis 0 of a swapped tensor, with infinite maxima replaced by zero.
+    fn stable_max(swapped: &B::T3Repr<D2, D1, D0>) -> B::T2Repr<D1, D0>
+    where
+        T: Float,
+    {
+        let max = B::t3_max_axis::<D2, D1, D0, D1, D0>(swapped.clone(), 0.into());
+        B::t2_map(max, |m| if m.is_finite() { m } else { T::zero() })
+    }
+
+    /// `ln(sum(exp(x)))` over the last axis, shifted by the maximum so that large inputs do
+    /// not overflow.
+    pub fn logsumexp_last(self) -> Matrix<T, D0, D1, B>
+    where
+        T: Float,
+    {
+        let x = Self::swap_last(self.repr);
+        let max = Self::stable_max(&x);
+        let exp = B::t3_map(B::t3_t2_sub(x, max.clone()), T::exp);
+        let sum = B::t3_sum_axis::<D2, D1, D0, D1, D0>(exp, 0.into());
+        Matrix {
+            repr: B::t2_transpose(B::t2_t2_add(B::t2_map(sum, T::ln), max)),
+            shape: (D0, D1),
+        }
+    }
+
+    /// Normalizes `exp(x)` to sum to one over the last axis, as for attention scores.
+    pub fn softmax_last(self) -> Self
+    where
+        T: Float,
+    {
+        let x = Self::swap_last(self.repr);
+        let max = Self::stable_max(&x);
+        let exp = B::t3_map(B::t3_t2_sub(x, max), T::exp);
+        let sum = B::t3_sum_axis::<D2, D1, D0, D1, D0>(exp.clone(), 0.into());
+        Self {
+            repr: Self::swap_last(B::t3_t2_div(exp, sum)),
+            shape: self.shape,
+        }
+    }
+
+    /// The logarithm of [`softmax_last`](Self::softmax_last), computed as
+    /// `x - logsumexp(x)`.
+    pub fn log_softmax_last(self) -> Self
+    where
+        T: Float,
+    {
+        let x = Self::swap_last(self.repr);
+        let max = Self::stable_max(&x);
+        let shifted = B::t3_t2_sub(x, max);
+        let sum =
+            B::t3_sum_axis::<D2, D1, D0, D1, D0>(B::t3_map(shifted.clone(), T::exp), 0.into());
+        Self {
+            repr: Self::swap_last(B::t3_t2_sub(shifted, B::t2_map(sum, T::ln))),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Tensor
+    for Tensor3<T, D0, D1, D2, B>
+{
+    type Shape = (usize, usize, usize);
+    type DataType = T;
+
+    fn shape(&self) -> Self::Shape {
+        self.shape
+    }
+
+    fn zeros() -> Self
+    where
+        Self::DataType: Zero + Copy,
+    {
+        Self {
+            repr: B::t3_zeros(),
+            shape: (D0, D1, D2),
+        }
+    }
+
+    fn ones() -> Self
+    where
+        Self::DataType: One + Copy,
+    {
+        Self {
+            repr: B::t3_ones(),
+            shape: (D0, D1, D2),
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Add<Scalar<T, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t3_t0_add(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+/// Broadcasts the vector over the leading axes, adding it to every row along the last axis.
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Add<Vector<T, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, other: Vector<T, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t1_add(self.repr, other.repr, 2.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+/// Broadcasts the matrix over the leading axis, adding it to every `D1 x D2` slice.
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Add<Matrix<T, D1, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, other: Matrix<T, D1, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t2_add(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Add
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t3_t3_add(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Sub<Scalar<T, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t3_t0_sub(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Sub<Vector<T, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Vector<T, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t1_sub(self.repr, other.repr, 2.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Sub<Matrix<T, D1, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, other: Matrix<T, D1, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t2_sub(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Sub
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t3_t3_sub(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Mul<Scalar<T, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t3_t0_mul(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Mul<Vector<T, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Vector<T, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t1_mul(self.repr, other.repr, 2.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Mul<Matrix<T, D1, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, other: Matrix<T, D1, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t2_mul(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Mul
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t3_t3_mul(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Div<Scalar<T, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t3_t0_div(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Div<Vector<T, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Vector<T, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t1_div(self.repr, other.repr, 2.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Div<Matrix<T, D1, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, other: Matrix<T, D1, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t2_div(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Div
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t3_t3_div(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Rem<Scalar<T, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Scalar<T, B>) -> Self {
+        Self {
+            repr: B::t3_t0_rem(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Rem<Vector<T, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Vector<T, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t1_rem(self.repr, other.repr, 2.into()),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Rem<Matrix<T, D1, D2, B>>
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, other: Matrix<T, D1, D2, B>) -> Self {
+        Self {
+            repr: B::t3_t2_rem(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Rem
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        assert_eq!(self.shape, other.shape);
+
+        Self {
+            repr: B::t3_t3_rem(self.repr, other.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+impl<T, const D0: usize, const D1: usize, const D2: usize, B: Backend<T>> Neg
+    for Tensor3<T, D0, D1, D2, B>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            repr: B::t3_neg(self.repr),
+            shape: self.shape,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::array;
+
+    fn iota() -> Tensor3<i32, 2, 2, 3> {
+        Tensor3::from_array([[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]])
+    }
+
+    #[test]
+    fn test_get() {
+        let mut t = iota();
+        assert_eq!(t.get((1, 0, 2)), Some(&9));
+        assert_eq!(t.get((2, 0, 0)), None);
+        assert_eq!(t.get((0, 2, 0)), None);
+        assert_eq!(t.get((0, 0, 3)), None);
+        assert_eq!(unsafe { *t.get_unchecked((1, 1, 1)) }, 11);
+
+        t.set((0, 1, 2), 0);
+        t[(1, 1, 0)] += 1;
+        assert_eq!(t[(0, 1, 2)], 0);
+        assert_eq!(t[(1, 1, 0)], 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (1, 2, 0) out of bounds for shape (2, 2, 3)")]
+    fn test_index_out_of_bounds() {
+        let _ = iota()[(1, 2, 0)];
+    }
+
+    #[test]
+    fn test_elementwise_ops() {
+        let a: Tensor3<i32, 2, 1, 2> = Tensor3::from_array([[[1, 2]], [[3, 4]]]);
+        let b: Tensor3<i32, 2, 1, 2> = Tensor3::from_array([[[4, 3]], [[2, 1]]]);
+        let c = -(a - b) * Scalar::new(2) + Vector::from_array([1, 0]);
+        assert_eq!(c[(0, 0, 0)], 7);
+        assert_eq!(c[(0, 0, 1)], 2);
+        assert_eq!(c[(1, 0, 0)], -1);
+        assert_eq!(c[(1, 0, 1)], -6);
+
+        let m: Matrix<i32, 2, 3> = Matrix::from_array([[10, 20, 30], [40, 50, 60]]);
+        let d = iota() + m.clone();
+        assert_eq!(d[(0, 0, 1)], 22);
+        assert_eq!(d[(1, 1, 2)], 72);
+        let e = iota() % Scalar::new(5) * m;
+        assert_eq!(e[(1, 1, 1)], 50);
+        assert_eq!(e[(1, 0, 2)], 120);
+    }
+
+    #[test]
+    fn test_add_along() {
+        let d0 = iota().add_along::<0>(Vector::from_array([100, 200]));
+        let d1 = iota().add_along::<1>(Vector::from_array([10, 20]));
+        let d2 = iota().mul_along::<2>(Vector::from_array([1, 0, -1]));
+        assert_eq!(d0[(1, 0, 0)], 207);
+        assert_eq!(d0[(0, 1, 2)], 106);
+        assert_eq!(d1[(0, 1, 2)], 26);
+        assert_eq!(d1[(1, 0, 0)], 17);
+        assert_eq!(d2[(1, 1, 0)], 10);
+        assert_eq!(d2[(1, 1, 1)], 0);
+        assert_eq!(d2[(0, 0, 2)], -3);
+    }
+
+    #[test]
+    fn test_reductions() {
+        let sums = iota().sum_axis::<0>();
+        assert_eq!(sums.shape(), (2, 3));
+        assert_eq!(sums[(0, 0)], 8);
+        assert_eq!(sums[(1, 2)], 18);
+
+        let maxima = iota().max_axis::<1>();
+        assert_eq!(maxima.shape(), (2, 3));
+        assert_eq!(maxima[(0, 0)], 4);
+        assert_eq!(maxima[(1, 2)], 12);
+
+        let logits: Tensor3<f32, 1, 2, 3> =
+            Tensor3::from_array([[[0.1, 0.7, 0.2], [0.5, 0.3, 0.2]]]);
+        let predictions = logits.clone().argmax_axis::<2>();
+        assert_eq!(predictions.shape(), (1, 2));
+        assert_eq!(predictions[(0, 0)], 1);
+        assert_eq!(predictions[(0, 1)], 0);
+        assert!((logits.mean_axis::<2>()[(0, 1)] - 1.0 / 3.0).abs() < 1e-6);
+
+        assert_eq!(*iota().sum().get(), 78);
+        assert_eq!(*iota().min().get(), 1);
+        let x: Tensor3<f64, 1, 2, 2> = Tensor3::from_array([[[1.0, 2.0], [3.0, 4.0]]]);
+        assert_eq!(*x.mean().get(), 2.5);
+    }
+
+    #[test]
+    fn test_reshape_and_permute() {
+        let m: Matrix<i32, 4, 3> = iota().reshape2::<4, 3>();
+        assert_eq!(m[(2, 0)], 7);
+        let r: Tensor3<i32, 3, 2, 2> = iota().reshape3::<3, 2, 2>();
+        assert_eq!(r[(1, 0, 1)], 6);
+        assert_eq!(iota().flatten()[11], 12);
+
+        let t = iota().permute::<2, 1, 0>();
+        assert_eq!(t.shape(), (3, 2, 2));
+        assert_eq!(t[(2, 0, 1)], 9);
+        let s = iota().permute::<0, 2, 1>();
+        assert_eq!(s.shape(), (2, 3, 2));
+        assert_eq!(s[(1, 2, 0)], 9);
+    }
+
+    #[test]
+    fn test_softmax_last() {
+        let scores: Tensor3<f64, 2, 1, 3, array::Backend> =
+            Tensor3::from_array([[[1000.0, 1001.0, 1002.0]], [[0.0, 0.0, 0.0]]]);
+        let p = scores.softmax_last();
+        assert!((p[(0, 0, 2)] - 0.6652409557748219).abs() < 1e-12);
+        assert!((p[(1, 0, 1)] - 1.0 / 3.0).abs() < 1e-12);
+
+        let scores: Tensor3<f64, 1, 2, 2> = Tensor3::from_array([[[0.0, 0.0], [3.0, 1000.0]]]);
+        let lse = scores.clone().logsumexp_last();
+        assert_eq!(lse.shape(), (1, 2));
+        assert!((lse[(0, 0)] - 2.0f64.ln()).abs() < 1e-12);
+        assert_eq!(lse[(0, 1)], 1000.0);
+        let log = scores.log_softmax_last();
+        assert!((log[(0, 0, 1)] + 2.0f64.ln()).abs() < 1e-12);
+        assert_eq!(log[(0, 1, 0)], -997.0);
+    }
+
+    #[test]
+    fn test_bmm() {
+        let a: Tensor3<i32, 2, 1, 2> = Tensor3::from_array([[[1, 2]], [[3, 4]]]);
+        let b: Tensor3<i32, 2, 2, 2> = Tensor3::from_array([[[1, 0], [0, 1]], [[1, 1], [2, 0]]]);
+        let c = a.bmm(b);
+        assert_eq!(c.shape(), (2, 1, 2));
+        assert_eq!((c[(0, 0, 0)], c[(0, 0, 1)]), (1, 2));
+        assert_eq!((c[(1, 0, 0)], c[(1, 0, 1)]), (11, 3));
+
+        let a: Tensor3<f64, 1, 2, 2, array::Backend> =
+            Tensor3::from_array([[[1.0, 2.0], [3.0, 4.0]]]);
+        let b: Tensor3<f64, 1, 2, 1, array::Backend> = Tensor3::from_array([[[1.0], [-1.0]]]);
+        assert_eq!(a.bmm(b).repr, [[[-1.0], [-1.0]]]);
+    }
+
+    #[test]
+    fn test_matmul() {
+        let a: Tensor3<i32, 2, 2, 2> = Tensor3::from_array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        let b: Matrix<i32, 2, 3> = Matrix::from_array([[1, 0, 1], [0, 1, 1]]);
+        let c = a.matmul(b);
+        assert_eq!(c.shape(), (2, 2, 3));
+        assert_eq!((c[(0, 1, 0)], c[(0, 1, 1)], c[(0, 1, 2)]), (3, 4, 7));
+        assert_eq!((c[(1, 0, 0)], c[(1, 0, 1)], c[(1, 0, 2)]), (5, 6, 11));
+
+        let a: Tensor3<u8, 1, 1, 2, array::Backend> = Tensor3::from_array([[[2, 3]]]);
+        let b: Matrix<u8, 2, 2, array::Backend> = Matrix::from_array([[1, 1], [1, 0]]);
+        assert_eq!(a.matmul(b).repr, [[[5, 2]]]);
+    }
 }